@@ -3,10 +3,7 @@ use std::{env, io::Read};
 use dotenvy::dotenv;
 use simd_adler32::Adler32;
 
-use open_lark::{
-    client::LarkClientBuilder,
-    service::drive::v1::files::UploadAllRequest,
-};
+use open_lark::{client::LarkClientBuilder, service::drive::v1::files::UploadAllRequest};
 
 /// 向云空间指定目录下上传一个小文件
 #[tokio::main]
@@ -33,7 +30,7 @@ async fn main() {
         .parent_node("nodcnBh4MAgg2GpI5IkRVZuw3Jd")
         .size(file_size as i32)
         .checksum(checksum.to_string())
-        .file(buffer.into())
+        .file(buffer)
         .build();
 
     // 发起请求
@@ -1,11 +1,10 @@
-use std::env;
-
 use dotenvy::dotenv;
 use serde_json::json;
 use uuid::Uuid;
 
 use open_lark::{
     client::LarkClientBuilder,
+    service::common::ReceiveIdType,
     service::im::v1::message::{
         ANode, AtNode, CreateMessageRequest, CreateMessageRequestBody, EmotionNode, ImgNode,
         MessageCardTemplate, MessagePost, MessagePostNode, MessageText, SendMessageTrait, TextNode,
@@ -18,10 +17,8 @@ use open_lark::{
 async fn main() {
     dotenv().expect(".env file not found");
     env_logger::init();
-    let app_id = env::var("APP_ID").unwrap();
-    let app_secret = env::var("APP_SECRET").unwrap();
-    // 创建 Client
-    let client = LarkClientBuilder::new(&app_id, &app_secret).build();
+    // 从环境变量创建 Client
+    let client = LarkClientBuilder::from_env().unwrap().build();
     let uuid = Uuid::new_v4();
     // 文本
     let _text_message = MessageText::new("hello world")
@@ -51,7 +48,7 @@ async fn main() {
     );
 
     let req = CreateMessageRequest::builder()
-        .receive_id_type("chat_id")
+        .receive_id_type(ReceiveIdType::ChatId)
         .request_body(
             CreateMessageRequestBody::builder()
                 .receive_id("oc_84d53efe245072c16ba4b4ff597f52f3")
@@ -68,7 +65,7 @@ async fn main() {
             println!("response: {:?}", resp);
         }
         Err(err) => {
-            println!("send message http error: {} ", err.to_string());
+            println!("send message http error: {} ", err);
         }
     }
 }
@@ -1,5 +1,9 @@
 use dotenvy::dotenv;
-use open_lark::client::ws::LarkWsClient;
+use open_lark::{
+    client::ws::LarkWsClient,
+    event::{EventDispatcher, EventV2},
+};
+use serde_json::Value;
 use std::env;
 
 #[tokio::main]
@@ -9,7 +13,18 @@ async fn main() {
     let app_id = env::var("APP_ID").unwrap();
     let app_secret = env::var("APP_SECRET").unwrap();
 
-    let client = LarkWsClient::new(app_id, app_secret);
+    let event_dispatcher = EventDispatcher::new().register(
+        "im.message.receive_v1",
+        |event: EventV2<Value>| async move {
+            println!("{:?}", event.event);
+            Ok(())
+        },
+    );
+    let client = LarkWsClient::builder(app_id, app_secret)
+        .with_event_dispatcher(event_dispatcher)
+        .with_state_callback(|state| println!("connection state: {state:?}"))
+        .build();
 
-    client.start().await.unwrap();
+    let handle = client.start().await.unwrap();
+    handle.join().await.unwrap();
 }
@@ -12,9 +12,10 @@ use crate::{
         },
         text::CustomTextSize,
     },
-    service::im::v1::message::SendMessageTrait,
+    service::im::v1::message::{CardMessageTrait, SendMessageTrait},
 };
 
+pub mod callback;
 pub mod color;
 pub mod components;
 pub mod href;
@@ -44,6 +45,8 @@ impl SendMessageTrait for FeishuCard {
     }
 }
 
+impl CardMessageTrait for FeishuCard {}
+
 impl FeishuCard {
     pub fn new() -> Self {
         let lng = FeishuCardLanguage::ZhCN;
@@ -87,7 +90,7 @@ pub struct FeishuCardConfig {
     ///
     /// - true：允许
     /// - false：不允许
-    /// 默认值为 true，该字段要求飞书客户端的版本为 V3.31.0 及以上。
+    ///   默认值为 true，该字段要求飞书客户端的版本为 V3.31.0 及以上。
     #[serde(skip_serializing_if = "Option::is_none")]
     enable_forward: Option<bool>,
     /// 是否为共享卡片。取值：
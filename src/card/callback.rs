@@ -0,0 +1,308 @@
+//! 卡片回传交互
+//!
+//! 用户点击卡片中配置了 [`CallbackBehavior`](crate::card::interactions::CallbackBehavior)
+//! 或 [`FormBehavior`](crate::card::interactions::FormBehavior) 的组件后,
+//! 开放平台推送 `card.action.trigger` 回调, 处理函数可以在响应中返回 toast 提示或更新后的卡片。
+//!
+//! [卡片回传交互](https://open.feishu.cn/document/uAjLw4CM/ukzMukzMukzM/feishu-cards/card-callback-communication)
+use std::{collections::HashMap, future::Future};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    card::{FeishuCard, FeishuCardLanguage},
+    core::SDKResult,
+    event::{EventDispatcher, EventV2},
+    service::im::v1::message::MessageCardTemplate,
+};
+
+/// 卡片回传交互事件 `card.action.trigger`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardActionEvent {
+    /// 回调触发者信息
+    pub operator: CardActionOperator,
+    /// 更新卡片用的凭证, 有效期 30 分钟, 最多可更新 2 次
+    pub token: Option<String>,
+    /// 用户操作的交互组件信息
+    pub action: CardAction,
+    /// 卡片展示场景
+    pub host: Option<String>,
+    /// 卡片分发类型, 链接预览卡片时为 url_preview
+    pub delivery_type: Option<String>,
+    /// 卡片上下文
+    pub context: Option<CardActionContext>,
+}
+
+/// 回调触发者信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardActionOperator {
+    pub tenant_key: Option<String>,
+    pub user_id: Option<String>,
+    pub open_id: Option<String>,
+    pub union_id: Option<String>,
+}
+
+/// 用户操作的交互组件信息
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardAction {
+    /// 交互组件的标签
+    pub tag: String,
+    /// 交互组件绑定的回传参数
+    pub value: Option<Value>,
+    /// 交互组件的名称, 由开发者在组件 name 属性中设置
+    pub name: Option<String>,
+    /// 表单容器内用户提交的数据, key 为组件的 name
+    pub form_value: Option<HashMap<String, Value>>,
+    /// 输入框组件中用户输入的内容
+    pub input_value: Option<String>,
+    /// 单选下拉、人员选择、日期选择等组件中用户选择的选项
+    pub option: Option<String>,
+    /// 多选下拉、人员多选组件中用户选择的选项
+    pub options: Option<Vec<String>>,
+    /// 勾选器的勾选状态
+    pub checked: Option<bool>,
+    /// 用户当前所在的时区
+    pub timezone: Option<String>,
+}
+
+/// 卡片上下文
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CardActionContext {
+    /// 链接地址, 适用于链接预览场景
+    pub url: Option<String>,
+    /// 链接预览的 token
+    pub preview_token: Option<String>,
+    /// 卡片所在的消息 ID
+    pub open_message_id: Option<String>,
+    /// 卡片所在的会话 ID
+    pub open_chat_id: Option<String>,
+}
+
+/// 卡片回传交互的响应
+#[derive(Debug, Serialize, Default)]
+pub struct CardActionResponse {
+    /// 弹出的 toast 提示
+    #[serde(skip_serializing_if = "Option::is_none")]
+    toast: Option<CardToast>,
+    /// 更新后的卡片
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card: Option<CallbackCard>,
+}
+
+impl CardActionResponse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn toast(mut self, toast: CardToast) -> Self {
+        self.toast = Some(toast);
+        self
+    }
+
+    /// 使用卡片 JSON 更新卡片
+    pub fn card(mut self, card: FeishuCard) -> Self {
+        self.card = Some(CallbackCard::Raw(RawCard {
+            r#type: "raw".to_string(),
+            data: card,
+        }));
+        self
+    }
+
+    /// 使用卡片模板更新卡片
+    pub fn template(mut self, template: MessageCardTemplate) -> Self {
+        self.card = Some(CallbackCard::Template(template));
+        self
+    }
+}
+
+/// toast 提示
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CardToast {
+    /// 提示类型
+    r#type: ToastType,
+    /// 单语言提示文案
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    /// 多语言提示文案
+    #[serde(skip_serializing_if = "Option::is_none")]
+    i18n: Option<HashMap<FeishuCardLanguage, String>>,
+}
+
+impl CardToast {
+    pub fn new(r#type: ToastType, content: impl ToString) -> Self {
+        Self {
+            r#type,
+            content: Some(content.to_string()),
+            i18n: None,
+        }
+    }
+
+    pub fn info(content: impl ToString) -> Self {
+        Self::new(ToastType::Info, content)
+    }
+
+    pub fn success(content: impl ToString) -> Self {
+        Self::new(ToastType::Success, content)
+    }
+
+    pub fn error(content: impl ToString) -> Self {
+        Self::new(ToastType::Error, content)
+    }
+
+    pub fn warning(content: impl ToString) -> Self {
+        Self::new(ToastType::Warning, content)
+    }
+
+    /// 添加多语言提示文案
+    pub fn i18n(mut self, language: FeishuCardLanguage, content: impl ToString) -> Self {
+        self.i18n
+            .get_or_insert_with(HashMap::new)
+            .insert(language, content.to_string());
+        self
+    }
+}
+
+/// toast 提示类型
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ToastType {
+    Info,
+    Success,
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum CallbackCard {
+    Raw(RawCard),
+    Template(MessageCardTemplate),
+}
+
+#[derive(Debug, Serialize)]
+struct RawCard {
+    /// 固定值：raw
+    r#type: String,
+    data: FeishuCard,
+}
+
+impl EventDispatcher {
+    /// 卡片回传交互
+    pub fn on_card_action_trigger<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<CardActionEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<CardActionResponse>> + Send + 'static,
+    {
+        self.register_with_response("card.action.trigger", handler)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::{
+        card::{
+            callback::{CardActionResponse, CardToast},
+            FeishuCard, FeishuCardLanguage,
+        },
+        event::EventDispatcher,
+        service::im::v1::message::MessageCardTemplate,
+    };
+
+    const PAYLOAD: &str = r#"{
+        "schema": "2.0",
+        "header": {
+            "event_id": "f7984f25108f8137722bb63c2d7bd9aa",
+            "token": "066zT6pS4QCbgj5Do145GfDbbagCHGgF",
+            "create_time": "1603977298000000",
+            "event_type": "card.action.trigger",
+            "tenant_key": "2df73991750f175e",
+            "app_id": "cli_a5d611352af9d00b"
+        },
+        "event": {
+            "operator": {
+                "tenant_key": "2df73991750f175e",
+                "user_id": "867gb1gc",
+                "open_id": "ou_3c14f3a59eaf2825dbe25359f15a8e2d",
+                "union_id": "on_cad4860e7af114fb4ff6c5d496d1dd76"
+            },
+            "token": "c-295ee57216a5dc9de90fefd0aadb4b1d7d337bc0",
+            "action": {
+                "value": {"key": "approve"},
+                "tag": "button",
+                "name": "submit",
+                "form_value": {"reason": "ok", "count": 3},
+                "timezone": "Asia/Shanghai"
+            },
+            "host": "im_message",
+            "context": {
+                "open_message_id": "om_574d639e4a44e4dd646eaf628e2f5b2f",
+                "open_chat_id": "oc_e4d2605ca917e695f54f11aaf56271b4"
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_toast_response() {
+        let resp = CardActionResponse::new()
+            .toast(CardToast::success("提交成功").i18n(FeishuCardLanguage::EnUS, "submitted"));
+        assert_eq!(
+            serde_json::to_value(resp).unwrap(),
+            json!({
+                "toast": {
+                    "type": "success",
+                    "content": "提交成功",
+                    "i18n": {"en_us": "submitted"}
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_card_response() {
+        let resp = CardActionResponse::new().template(MessageCardTemplate::new(
+            "AAqk1xm9Ftw35",
+            json!({"name": "foo"}),
+        ));
+        assert_eq!(
+            serde_json::to_value(resp).unwrap(),
+            json!({
+                "card": {
+                    "type": "template",
+                    "data": {
+                        "template_id": "AAqk1xm9Ftw35",
+                        "template_variable": {"name": "foo"}
+                    }
+                }
+            })
+        );
+
+        let resp =
+            serde_json::to_value(CardActionResponse::new().card(FeishuCard::default())).unwrap();
+        assert_eq!(resp["card"]["type"], "raw");
+        assert!(resp["card"]["data"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_on_card_action_trigger() {
+        let dispatcher = EventDispatcher::new().on_card_action_trigger(|event| async move {
+            let action = event.event.action;
+            assert_eq!(action.tag, "button");
+            assert_eq!(action.value.unwrap()["key"], "approve");
+            assert_eq!(action.form_value.unwrap()["count"], 3);
+            assert_eq!(
+                event.event.context.unwrap().open_chat_id.as_deref(),
+                Some("oc_e4d2605ca917e695f54f11aaf56271b4")
+            );
+            Ok(CardActionResponse::new().toast(CardToast::info("ok")))
+        });
+
+        let resp = dispatcher.dispatch(PAYLOAD.as_bytes()).await.unwrap();
+        assert_eq!(
+            resp.unwrap(),
+            json!({"toast": {"type": "info", "content": "ok"}})
+        );
+    }
+}
@@ -10,7 +10,7 @@ pub struct FeishuCardMultiImageLayout {
     /// - triple：三图混排，最多可排布三张图。
     /// - bisect：等分双列图混排，每行两个等大的正方形图，最多可排布三行，即六张图。
     /// - trisect：等分三列图混排，每行三个等大的正方形图，最多可排布三行，即九张图。
-    /// 注意：
+    ///   注意：
     ///
     /// 若上传的图片数量超过混排方式可容纳的上限，则系统将根据图片上传的顺序，
     /// 优先展示排列顺序中靠前的图片。超出上限的图片将不再显示。
@@ -1,70 +1,372 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use futures_util::{SinkExt, StreamExt};
 use kanal::AsyncSender;
-use log::{debug, error};
+use log::LevelFilter;
 use prost::Message as ProstMessage;
-use serde::Deserialize;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
-use tokio::sync::Mutex;
-use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use tokio::{
+    sync::{watch, Mutex},
+    task::JoinHandle,
+};
+use tokio_tungstenite::tungstenite::protocol::Message;
+use tokio_util::task::TaskTracker;
 use url::Url;
 
-use lark_websocket_protobuf::pbbp2::{Frame, Header};
+use lark_websocket_protobuf::pbbp2::Frame;
+
+use crate::{
+    client::ws::{
+        codec::{
+            new_ack_frame, new_ping_frame, new_response_frame, FrameExt, FrameType, MessageType,
+            HEADER_MESSAGE_ID, HEADER_SEQ, HEADER_SUM, HEADER_TRACE_ID,
+        },
+        connector::{ConnectOptions, WsStream},
+        frame_buffer::{FrameBuffer, DEFAULT_FRAME_TIMEOUT},
+    },
+    core::{
+        api_resp::BaseResponse, config::Config, constants::Domain, secret::Secret,
+        utils::user_agent,
+    },
+    event::EventDispatcher,
+};
+
+/// 按客户端设置的日志级别输出日志
+macro_rules! ws_log {
+    ($max_level:expr, $level:ident, $($arg:tt)+) => {
+        if log::Level::$level <= $max_level {
+            log::log!(log::Level::$level, $($arg)+);
+        }
+    };
+}
 
-use crate::core::{api_resp::BaseResponse, constants::FEISHU_BASE_URL};
+pub mod codec;
+mod connector;
+mod frame_buffer;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_server;
 
 const END_POINT_URL: &str = "/callback/ws/endpoint";
 
-#[derive(Debug, Clone)]
+/// 长连接的连接状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// 首次连接中
+    Connecting,
+    /// 已连接
+    Connected,
+    /// 断线后重连中
+    Reconnecting,
+    /// 已关闭, 不再重连
+    Closed,
+}
+
+/// 连接状态变化的回调
+pub type StateCallback = Arc<dyn Fn(ConnectionState) + Send + Sync>;
+
+#[derive(Clone)]
 pub struct LarkWsClient {
     app_id: String,
-    app_secret: String,
+    app_secret: Secret,
+
+    domain: Domain,
+    /// 连接地址中带有访问凭证
+    conn_url: Secret,
+    http_client: reqwest::Client,
+    connect_options: ConnectOptions,
+    event_dispatcher: EventDispatcher,
+    /// 断线后是否自动重连
+    auto_reconnect: bool,
+    /// 服务端下发的连接配置, 获取连接地址和收到 pong 帧时更新
+    client_config: ClientConfig,
+    state_callback: Option<StateCallback>,
+    state_tx: Arc<watch::Sender<ConnectionState>>,
+    /// 心跳间隔, 设置后不再使用服务端下发的间隔
+    ping_interval: Option<Duration>,
+    /// 获取连接地址时的语言, 影响错误信息的语言
+    locale: String,
+    log_level: LevelFilter,
+}
+
+impl Debug for LarkWsClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LarkWsClient")
+            .field("app_id", &self.app_id)
+            .field("domain", &self.domain)
+            .field("auto_reconnect", &self.auto_reconnect)
+            .field("client_config", &self.client_config)
+            .field("ping_interval", &self.ping_interval)
+            .field("locale", &self.locale)
+            .field("log_level", &self.log_level)
+            .field("event_dispatcher", &self.event_dispatcher)
+            .finish()
+    }
+}
 
-    domain: String,
-    conn_url: String,
+/// 建立好的连接, 以及连接地址中的服务 ID
+struct Connection {
+    stream: WsStream,
+    service_id: i32,
 }
 
 impl LarkWsClient {
     pub fn new(app_id: impl ToString, app_secret: impl ToString) -> Self {
         Self {
             app_id: app_id.to_string(),
-            app_secret: app_secret.to_string(),
-            domain: FEISHU_BASE_URL.to_string(),
-            conn_url: "".to_string(),
+            app_secret: app_secret.to_string().into(),
+            domain: Domain::Feishu,
+            conn_url: Secret::default(),
+            http_client: reqwest::Client::new(),
+            connect_options: ConnectOptions {
+                user_agent: user_agent(),
+                ..Default::default()
+            },
+            event_dispatcher: EventDispatcher::default(),
+            auto_reconnect: true,
+            client_config: ClientConfig::default(),
+            state_callback: None,
+            state_tx: Arc::new(watch::channel(ConnectionState::Closed).0),
+            ping_interval: None,
+            locale: "zh".to_string(),
+            log_level: LevelFilter::Trace,
+        }
+    }
+
+    /// 长连接客户端的构建器
+    pub fn builder(app_id: impl ToString, app_secret: impl ToString) -> LarkWsClientBuilder {
+        LarkWsClientBuilder {
+            client: Self::new(app_id, app_secret),
+        }
+    }
+
+    /// 复用客户端配置中的域名、代理、根证书和 User-Agent 设置
+    #[deprecated(note = "use `LarkWsClient::builder()` instead")]
+    pub fn with_config(mut self, config: &Config) -> Self {
+        self.domain = config.domain.clone();
+        self.http_client = config.http_client.clone();
+        self.connect_options = ConnectOptions {
+            proxy: config.proxy.clone(),
+            root_certificates: config.root_certificates.clone(),
+            user_agent: config.user_agent(),
+        };
+        self
+    }
+
+    /// 设置开放平台域名, 飞书或 Lark 国际版
+    #[deprecated(note = "use `LarkWsClient::builder()` instead")]
+    pub fn with_domain(mut self, domain: Domain) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// 设置事件分发器, 收到的事件交由分发器处理
+    #[deprecated(note = "use `LarkWsClient::builder()` instead")]
+    pub fn with_event_dispatcher(mut self, event_dispatcher: EventDispatcher) -> Self {
+        self.event_dispatcher = event_dispatcher;
+        self
+    }
+
+    /// 设置断线后是否自动重连, 默认开启
+    ///
+    /// 重连次数、间隔与随机等待时长以服务端下发的配置为准
+    #[deprecated(note = "use `LarkWsClient::builder()` instead")]
+    pub fn with_auto_reconnect(mut self, auto_reconnect: bool) -> Self {
+        self.auto_reconnect = auto_reconnect;
+        self
+    }
+
+    /// 设置连接状态变化的回调
+    #[deprecated(note = "use `LarkWsClient::builder()` instead")]
+    pub fn with_state_callback<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(ConnectionState) + Send + Sync + 'static,
+    {
+        self.state_callback = Some(Arc::new(callback));
+        self
+    }
+
+    /// 建立长连接, 在后台持续接收事件, 返回用于关闭连接和查询状态的句柄
+    ///
+    /// 首次连接遇到应用凭证错误等无法通过重连恢复的错误, 或未开启自动重连时直接返回错误。
+    /// 开启自动重连时, 断线后重新获取连接地址并重连, 直到超过服务端配置的重连次数
+    pub async fn start(mut self) -> WsResult<WsClientHandle> {
+        self.set_state(ConnectionState::Connecting);
+        let connection = match self.connect().await {
+            Ok(connection) => {
+                self.set_state(ConnectionState::Connected);
+                Some(connection)
+            }
+            Err(e) if !self.auto_reconnect || e.is_fatal() => {
+                self.set_state(ConnectionState::Closed);
+                return Err(e);
+            }
+            Err(e) => {
+                ws_log!(self.log_level, Error, "Failed to connect: {e}");
+                None
+            }
+        };
+
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let state_rx = self.state_tx.subscribe();
+        let task = tokio::spawn(self.supervise(connection, shutdown_rx));
+
+        Ok(WsClientHandle {
+            state_rx,
+            shutdown_tx,
+            task,
+        })
+    }
+
+    /// 维持连接, 断线后按配置重连, 直到关闭或重连失败
+    async fn supervise(
+        mut self,
+        mut connection: Option<Connection>,
+        mut shutdown_rx: watch::Receiver<bool>,
+    ) -> WsResult<()> {
+        loop {
+            let current = match connection.take() {
+                Some(connection) => connection,
+                None => {
+                    self.set_state(ConnectionState::Reconnecting);
+                    let reconnected = tokio::select! {
+                        result = self.reconnect() => Some(result?),
+                        _ = shutdown_signal(&mut shutdown_rx) => None,
+                    };
+                    match reconnected {
+                        Some(connection) => {
+                            self.set_state(ConnectionState::Connected);
+                            connection
+                        }
+                        None => {
+                            self.set_state(ConnectionState::Closed);
+                            return Ok(());
+                        }
+                    }
+                }
+            };
+
+            let shutdown = self.run(current, &mut shutdown_rx).await;
+            if shutdown || !self.auto_reconnect {
+                self.set_state(ConnectionState::Closed);
+                return Ok(());
+            }
+
+            ws_log!(self.log_level, Warn, "Disconnected, reconnecting");
         }
     }
 
-    pub async fn start(self) -> WsResult<()> {
-        Self::connect(self).await
+    fn set_state(&self, state: ConnectionState) {
+        ws_log!(self.log_level, Debug, "Connection state: {state:?}");
+        self.state_tx.send_replace(state);
+        if let Some(callback) = &self.state_callback {
+            callback(state);
+        }
     }
 
-    async fn connect(mut self) -> WsResult<()> {
+    /// 按服务端配置重连, 首次重连前随机等待, 避免大量客户端同时重连
+    async fn reconnect(&mut self) -> WsResult<Connection> {
+        tokio::time::sleep(reconnect_jitter(self.client_config.reconnect_nonce)).await;
+
+        let mut attempts = 0;
+        loop {
+            match self.connect().await {
+                Ok(connection) => {
+                    ws_log!(
+                        self.log_level,
+                        Info,
+                        "Reconnected after {} attempts",
+                        attempts + 1
+                    );
+                    return Ok(connection);
+                }
+                Err(e) if e.is_fatal() => {
+                    self.set_state(ConnectionState::Closed);
+                    return Err(e);
+                }
+                Err(e) => ws_log!(
+                    self.log_level,
+                    Warn,
+                    "Reconnect attempt {} failed: {e}",
+                    attempts + 1
+                ),
+            }
+
+            attempts += 1;
+            // 重连次数为负数时不限次数
+            let reconnect_count = self.client_config.reconnect_count;
+            if reconnect_count >= 0 && attempts >= reconnect_count {
+                self.set_state(ConnectionState::Closed);
+                return Err(WsClientError::ConnectError(format!(
+                    "unable to connect to server after {attempts} attempts"
+                )));
+            }
+            let interval = self.client_config.reconnect_interval.max(0) as u64;
+            tokio::time::sleep(Duration::from_secs(interval)).await;
+        }
+    }
+
+    /// 获取连接地址并建立连接
+    async fn connect(&mut self) -> WsResult<Connection> {
         let conn_url = self.get_conn_url().await?;
         let url = Url::parse(&conn_url)?;
 
         let query_pairs: HashMap<_, _> = url.query_pairs().into_iter().collect();
-        let conn_id = query_pairs.get("device_id").unwrap().to_string();
-        let service_id = query_pairs.get("service_id").unwrap().to_string();
+        let conn_id = query_pairs
+            .get("device_id")
+            .ok_or_else(|| WsClientError::ConnectError("missing device_id in url".to_string()))?
+            .to_string();
+        let service_id = query_pairs
+            .get("service_id")
+            .and_then(|service_id| service_id.parse().ok())
+            .ok_or_else(|| WsClientError::ConnectError("invalid service_id in url".to_string()))?;
+
+        self.conn_url = url.to_string().into();
 
-        self.conn_url = url.to_string();
+        let stream = connector::connect(&url, &self.connect_options).await?;
+        ws_log!(self.log_level, Info, "Connected, conn_id: {conn_id}");
 
-        let (ws_stream, _response) = connect_async(url).await?;
-        let (mut write, read) = ws_stream.split();
+        Ok(Connection { stream, service_id })
+    }
+
+    /// 收发消息直到连接断开或收到关闭信号, 收到关闭信号时返回 true
+    ///
+    /// 关闭时先停止心跳和接收新消息, 等待处理中的事件完成并回复后再发送关闭帧
+    async fn run(
+        &mut self,
+        connection: Connection,
+        shutdown_rx: &mut watch::Receiver<bool>,
+    ) -> bool {
+        let (mut write, read) = connection.stream.split();
         let (sender_tx, sender_rx) = kanal::unbounded_async::<Message>();
+        let log_level = self.log_level;
 
-        let write_task = async move {
+        let mut write_task = tokio::spawn(async move {
             while let Ok(msg) = sender_rx.recv().await {
-                match write.send(msg).await {
-                    Ok(_) => {}
-                    Err(e) => {
-                        error!("Error: {:?}", e);
-                    }
+                let close = matches!(msg, Message::Close(_));
+                if let Err(e) = write.send(msg).await {
+                    ws_log!(log_level, Error, "Failed to send message: {:?}", e);
+                    break;
+                }
+                if close {
+                    break;
                 }
             }
-        };
+        });
 
-        let ws_client = Client::new(conn_id, service_id, sender_tx.clone());
+        let ws_client = Client::new(
+            connection.service_id,
+            self.client_config.clone(),
+            sender_tx.clone(),
+            self.event_dispatcher.clone(),
+            self.log_level,
+        );
 
         let client = Arc::new(Mutex::new(ws_client));
         let read_client = Arc::clone(&client);
@@ -76,57 +378,91 @@ impl LarkWsClient {
                 match message {
                     Ok(msg) => {
                         let mut new_client = read_client.lock().await;
-                        new_client.handle_message(msg).unwrap();
+                        if let Err(e) = new_client.handle_message(msg) {
+                            ws_log!(log_level, Error, "Failed to handle message: {:?}", e);
+                        }
                     }
                     Err(e) => {
-                        println!("Error: {:?}", e);
+                        ws_log!(log_level, Error, "Failed to read message: {:?}", e);
+                        break;
                     }
                 }
             }
         };
 
         let ping_client = Arc::clone(&client);
+        let ping_interval = self.ping_interval;
 
         let ping_task = async move {
             loop {
-                let ping_client = ping_client.lock().await;
-                let service_id: i32 = ping_client.service_id.parse().unwrap();
-                let frame = new_frame(service_id);
-                let msg = Message::Binary(frame.encode_to_vec());
-                debug!("Sending ping message: {:?}", msg);
-                ping_client.sender_tx.send(msg).await.unwrap();
-                tokio::time::sleep(tokio::time::Duration::from_secs(
-                    ping_client.ping_interval as u64,
-                ))
-                .await;
+                // 发送后释放锁, 避免休眠期间阻塞读取任务
+                let interval = {
+                    let ping_client = ping_client.lock().await;
+                    let frame = new_ping_frame(ping_client.service_id);
+                    let msg = Message::Binary(frame.encode_to_vec());
+                    ws_log!(log_level, Debug, "Sending ping message: {:?}", msg);
+                    if ping_client.sender_tx.send(msg).await.is_err() {
+                        break;
+                    }
+                    ping_interval.unwrap_or_else(|| {
+                        Duration::from_secs(ping_client.config.ping_interval.max(1) as u64)
+                    })
+                };
+                tokio::time::sleep(interval).await;
             }
         };
 
-        tokio::select! {
-            _ = write_task => {}
-            _ = read_task => {}
-            _ = ping_task => {}
+        let shutdown = tokio::select! {
+            _ = &mut write_task => false,
+            _ = read_task => false,
+            _ = ping_task => false,
+            _ = shutdown_signal(shutdown_rx) => true,
+        };
+
+        // 保留 pong 帧中下发的配置, 用于重连
+        let tracker = {
+            let client = client.lock().await;
+            self.client_config = client.config.clone();
+            client.tracker.clone()
+        };
+
+        if shutdown {
+            tracker.close();
+            ws_log!(
+                self.log_level,
+                Debug,
+                "Waiting for {} in-flight handlers",
+                tracker.len()
+            );
+            tracker.wait().await;
+            if sender_tx.send(Message::Close(None)).await.is_ok() {
+                let _ = write_task.await;
+            }
+            ws_log!(self.log_level, Info, "Connection closed");
+        } else {
+            write_task.abort();
         }
 
-        Ok(())
+        shutdown
     }
 
     /// 获取连接地址
     async fn get_conn_url(&mut self) -> WsResult<String> {
         let body = json!({
             "AppID": self.app_id,
-            "AppSecret": self.app_secret
+            "AppSecret": self.app_secret.expose()
         });
 
-        let req = reqwest::Client::new()
-            .post(&format!("{}{END_POINT_URL}", self.domain))
-            .header("locale", "zh")
+        let req = self
+            .http_client
+            .post(format!("{}{END_POINT_URL}", self.domain.open_base_url()))
+            .header("locale", &self.locale)
             .json(&body)
             .send()
             .await?;
 
         let resp = req.json::<BaseResponse<EndPointResponse>>().await?;
-        debug!("{:?}", resp.data);
+        ws_log!(self.log_level, Debug, "{:?}", resp.data);
 
         if !resp.success() {
             return match resp.raw_response.code {
@@ -145,165 +481,374 @@ impl LarkWsClient {
             };
         }
 
-        let end_point = resp.data.unwrap();
-        if end_point.url.is_none() || end_point.url.as_ref().unwrap().is_empty() {
-            return Err(WsClientError::ServerError {
-                code: 500,
-                message: "No available endpoint".to_string(),
-            });
+        let no_endpoint = || WsClientError::ServerError {
+            code: 500,
+            message: "No available endpoint".to_string(),
+        };
+        let end_point = resp.data.ok_or_else(no_endpoint)?;
+        if let Some(client_config) = end_point.client_config {
+            self.client_config = client_config;
         }
 
-        Ok(end_point.url.unwrap())
+        match end_point.url {
+            Some(url) if !url.is_empty() => Ok(url.expose().to_string()),
+            _ => Err(no_endpoint()),
+        }
     }
 }
 
 #[derive(Debug, Clone)]
 struct Client {
-    auto_reconnect: bool,
-    reconnect_count: i32,
-    reconnect_interval: i32,
-    reconnect_nonce: i32,
-    ping_interval: i32,
-    conn_id: String,
-    service_id: String,
+    config: ClientConfig,
+    service_id: i32,
     sender_tx: AsyncSender<Message>,
+    event_dispatcher: EventDispatcher,
+    frame_buffer: FrameBuffer,
+    /// 处理中的事件, 关闭连接前等待其完成
+    tracker: TaskTracker,
+    log_level: LevelFilter,
 }
 
 impl Client {
-    pub fn new(conn_id: String, service_id: String, sender_tx: AsyncSender<Message>) -> Self {
+    pub fn new(
+        service_id: i32,
+        config: ClientConfig,
+        sender_tx: AsyncSender<Message>,
+        event_dispatcher: EventDispatcher,
+        log_level: LevelFilter,
+    ) -> Self {
         Self {
-            auto_reconnect: true,
-            reconnect_count: 30,
-            reconnect_interval: -1,
-            reconnect_nonce: 2 * 60,
-            ping_interval: 2 * 60,
-            conn_id,
+            config,
             service_id,
             sender_tx,
+            event_dispatcher,
+            frame_buffer: FrameBuffer::new(DEFAULT_FRAME_TIMEOUT, log_level),
+            tracker: TaskTracker::new(),
+            log_level,
         }
     }
 
     fn configure(&mut self, config: ClientConfig) {
-        self.reconnect_count = config.reconnect_count;
-        self.reconnect_interval = config.reconnect_interval;
-        self.reconnect_nonce = config.reconnect_nonce;
-        self.ping_interval = config.ping_interval;
+        self.config = config;
     }
 
     fn handle_message(&mut self, message: Message) -> WsResult<()> {
         match message {
             Message::Text(text) => {
-                debug!("Received a text message: {:?}", text);
+                ws_log!(self.log_level, Debug, "Received a text message: {:?}", text);
             }
             Message::Binary(bin) => {
                 let frame = Frame::decode(&*bin)?;
-                debug!("Received a binary message: {:?}", frame);
-                match frame.method {
-                    // FrameTypeControl
-                    0 => self.handle_control_frame(frame),
-                    // FrameTypeData
-                    1 => self.handle_data_frame(frame),
-
-                    _ => {}
+                ws_log!(
+                    self.log_level,
+                    Debug,
+                    "Received a binary message: {:?}",
+                    frame
+                );
+                match frame.frame_type()? {
+                    FrameType::Control => self.handle_control_frame(frame)?,
+                    FrameType::Data => self.handle_data_frame(frame)?,
                 }
             }
             Message::Ping(ping) => {
-                debug!("Received a ping message {:?}", ping);
+                ws_log!(self.log_level, Debug, "Received a ping message {:?}", ping);
             }
             Message::Pong(pong) => {
-                debug!("Received a pong message {:?}", pong);
+                ws_log!(self.log_level, Debug, "Received a pong message {:?}", pong);
             }
             Message::Close(close) => {
-                debug!("Received a close message: {:?}", close);
+                ws_log!(
+                    self.log_level,
+                    Debug,
+                    "Received a close message: {:?}",
+                    close
+                );
             }
             Message::Frame(frame) => {
-                debug!("Received a frame message: {:?}", frame);
+                ws_log!(
+                    self.log_level,
+                    Debug,
+                    "Received a frame message: {:?}",
+                    frame
+                );
             }
         }
 
         Ok(())
     }
 
-    fn handle_control_frame(&mut self, frame: Frame) {
-        let headers = frame.headers;
-        let t = headers.iter().find(|h| h.key == "type").unwrap();
-        if t.value.to_string() == *"pong" {
-            debug!("Received a pong frame");
-            let config = serde_json::from_slice::<ClientConfig>(&frame.payload.unwrap()).unwrap();
-            self.configure(config);
+    fn handle_control_frame(&mut self, frame: Frame) -> WsResult<()> {
+        if frame.message_type()? == MessageType::Pong {
+            ws_log!(self.log_level, Debug, "Received a pong frame");
+            if let Some(payload) = &frame.payload {
+                let config = serde_json::from_slice::<ClientConfig>(payload)
+                    .map_err(|e| WsClientError::InvalidFrame(format!("invalid pong: {e}")))?;
+                self.configure(config);
+            }
         }
+
+        Ok(())
     }
 
-    fn handle_data_frame(&mut self, frame: Frame) {
-        let headers = frame.headers;
-        // 拆包数, 未拆包为1
-        let sum: i32 = headers
-            .iter()
-            .find(|h| h.key == "sum")
-            .unwrap()
-            .value
-            .parse()
-            .unwrap();
-        // 包序号, 未拆包为0
-        let seq: i32 = headers
-            .iter()
-            .find(|h| h.key == "seq")
-            .unwrap()
-            .value
-            .parse()
-            .unwrap();
-        let type_ = headers
-            .iter()
-            .find(|h| h.key == "type")
-            .unwrap()
-            .value
-            .as_str();
-        //  消息ID, 拆包后继承
-        let message_id = headers
-            .iter()
-            .find(|h| h.key == "message_id")
-            .unwrap()
-            .value
-            .as_str();
-        // 链路ID
-        let trace_id = headers
-            .iter()
-            .find(|h| h.key == "trace_id")
-            .unwrap()
-            .value
-            .as_str();
-
-        let payload = frame.payload.unwrap();
-        if sum > 1 {
-            debug!("Received a multi-frame message");
+    fn handle_data_frame(&mut self, mut frame: Frame) -> WsResult<()> {
+        let sum: usize = frame.parse_header(HEADER_SUM)?;
+        let seq: usize = frame.parse_header(HEADER_SEQ)?;
+        let message_type = frame.message_type()?;
+        let message_id = frame.header(HEADER_MESSAGE_ID)?.to_string();
+        let trace_id = frame
+            .get_header(HEADER_TRACE_ID)
+            .unwrap_or_default()
+            .to_string();
+
+        if message_type != MessageType::Event && message_type != MessageType::Card {
+            ws_log!(
+                self.log_level,
+                Debug,
+                "Ack a {message_type} frame without handling, message_id: {message_id}"
+            );
+            let frame = new_ack_frame(frame);
+            let _ = self
+                .sender_tx
+                .try_send(Message::Binary(frame.encode_to_vec()));
+            return Ok(());
         }
 
-        if type_ == "data" {
-            debug!("Received a data frame");
+        let payload = frame.payload.take().unwrap_or_default();
+        if sum > 1 {
+            ws_log!(
+                self.log_level,
+                Debug,
+                "Received a multi-frame message, seq: {seq}/{sum}"
+            );
         }
+        let Some(payload) = self.frame_buffer.push(&message_id, sum, seq, payload) else {
+            return Ok(());
+        };
+
+        ws_log!(
+            self.log_level,
+            Debug,
+            "Received a {message_type} frame, message_id: {message_id}, trace_id: {trace_id}"
+        );
+        let event_dispatcher = self.event_dispatcher.clone();
+        let sender_tx = self.sender_tx.clone();
+        let log_level = self.log_level;
+        self.tracker.spawn(async move {
+            let start = Instant::now();
+            let (code, data) = match event_dispatcher.dispatch(&payload).await {
+                Ok(data) => (200, data),
+                Err(e) => {
+                    ws_log!(log_level, Error, "Failed to handle {message_type}: {:?}", e);
+                    (500, None)
+                }
+            };
+
+            let frame = new_response_frame(frame, code, data, start.elapsed().as_millis());
+            if let Err(e) = sender_tx.send(Message::Binary(frame.encode_to_vec())).await {
+                ws_log!(log_level, Error, "Failed to send response frame: {:?}", e);
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// 长连接客户端的构建器
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// use log::LevelFilter;
+/// use open_lark::{client::ws::LarkWsClient, event::EventDispatcher};
+///
+/// let client = LarkWsClient::builder("app_id", "app_secret")
+///     .with_event_dispatcher(EventDispatcher::new())
+///     .with_ping_interval(Duration::from_secs(30))
+///     .with_log_level(LevelFilter::Info)
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct LarkWsClientBuilder {
+    client: LarkWsClient,
+}
+
+impl LarkWsClientBuilder {
+    /// 复用已有客户端的配置, 包括应用凭证、域名、HTTP 客户端、代理和根证书
+    pub fn from_config(config: &Config) -> Self {
+        LarkWsClient::builder(&config.app_id, config.app_secret.expose()).with_config(config)
+    }
+
+    /// 复用配置中的域名、HTTP 客户端、代理、根证书和 User-Agent 设置
+    pub fn with_config(mut self, config: &Config) -> Self {
+        self.client.domain = config.domain.clone();
+        self.client.http_client = config.http_client.clone();
+        self.client.connect_options = ConnectOptions {
+            proxy: config.proxy.clone(),
+            root_certificates: config.root_certificates.clone(),
+            user_agent: config.user_agent(),
+        };
+        self
+    }
+
+    /// 设置开放平台域名, 飞书或 Lark 国际版
+    pub fn with_domain(mut self, domain: Domain) -> Self {
+        self.client.domain = domain;
+        self
+    }
+
+    /// 设置事件分发器, 收到的事件交由分发器处理
+    pub fn with_event_dispatcher(mut self, event_dispatcher: EventDispatcher) -> Self {
+        self.client.event_dispatcher = event_dispatcher;
+        self
+    }
+
+    /// 设置断线后是否自动重连, 默认开启
+    ///
+    /// 重连次数、间隔与随机等待时长以服务端下发的配置为准
+    pub fn with_auto_reconnect(mut self, auto_reconnect: bool) -> Self {
+        self.client.auto_reconnect = auto_reconnect;
+        self
+    }
+
+    /// 设置心跳间隔, 不设置时使用服务端下发的间隔
+    pub fn with_ping_interval(mut self, ping_interval: Duration) -> Self {
+        self.client.ping_interval = Some(ping_interval);
+        self
+    }
+
+    /// 设置获取连接地址使用的 HTTP 客户端
+    pub fn with_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.client.http_client = http_client;
+        self
+    }
+
+    /// 设置获取连接地址时的语言, 默认为 zh
+    pub fn with_locale(mut self, locale: impl ToString) -> Self {
+        self.client.locale = locale.to_string();
+        self
+    }
+
+    /// 设置长连接客户端的日志级别, 默认不额外过滤, 以全局日志配置为准
+    pub fn with_log_level(mut self, log_level: LevelFilter) -> Self {
+        self.client.log_level = log_level;
+        self
+    }
+
+    /// 设置连接状态变化的回调
+    pub fn with_state_callback<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(ConnectionState) + Send + Sync + 'static,
+    {
+        self.client.state_callback = Some(Arc::new(callback));
+        self
+    }
+
+    pub fn build(self) -> LarkWsClient {
+        self.client
+    }
+}
+
+/// 长连接客户端的运行句柄
+#[derive(Debug)]
+pub struct WsClientHandle {
+    state_rx: watch::Receiver<ConnectionState>,
+    shutdown_tx: watch::Sender<bool>,
+    task: JoinHandle<WsResult<()>>,
+}
+
+impl WsClientHandle {
+    /// 当前的连接状态
+    pub fn state(&self) -> ConnectionState {
+        *self.state_rx.borrow()
+    }
+
+    /// 订阅连接状态的变化, 可用于健康检查
+    pub fn subscribe(&self) -> watch::Receiver<ConnectionState> {
+        self.state_rx.clone()
+    }
+
+    /// 关闭连接
+    ///
+    /// 停止心跳和接收新消息, 等待处理中的事件完成并回复后发送关闭帧, 不再重连
+    pub async fn shutdown(self) -> WsResult<()> {
+        let _ = self.shutdown_tx.send(true);
+        self.join().await
+    }
+
+    /// 等待客户端退出, 未开启自动重连时断线即退出, 否则在重连失败后退出
+    pub async fn join(self) -> WsResult<()> {
+        self.task.await?
+    }
+}
+
+/// 等待关闭信号, 句柄被丢弃时一直等待, 客户端在后台继续运行
+async fn shutdown_signal(shutdown_rx: &mut watch::Receiver<bool>) {
+    if shutdown_rx.wait_for(|shutdown| *shutdown).await.is_err() {
+        std::future::pending::<()>().await;
     }
 }
 
 #[derive(Debug, Deserialize)]
 pub struct EndPointResponse {
     #[serde(rename = "URL")]
-    pub url: Option<String>,
+    pub url: Option<Secret>,
     #[serde(rename = "ClientConfig")]
     pub client_config: Option<ClientConfig>,
 }
 
-#[derive(Debug, Deserialize)]
+/// 服务端下发的连接配置, 时间单位为秒
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientConfig {
+    /// 重连次数, 负数表示不限次数
     #[serde(rename = "ReconnectCount")]
     reconnect_count: i32,
+    /// 重连间隔
     #[serde(rename = "ReconnectInterval")]
     reconnect_interval: i32,
+    /// 首次重连前随机等待的最大时长
     #[serde(rename = "ReconnectNonce")]
     reconnect_nonce: i32,
+    /// 心跳间隔
     #[serde(rename = "PingInterval")]
     ping_interval: i32,
 }
 
+impl ClientConfig {
+    pub fn new(
+        reconnect_count: i32,
+        reconnect_interval: i32,
+        reconnect_nonce: i32,
+        ping_interval: i32,
+    ) -> Self {
+        Self {
+            reconnect_count,
+            reconnect_interval,
+            reconnect_nonce,
+            ping_interval,
+        }
+    }
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        Self {
+            reconnect_count: -1,
+            reconnect_interval: 2 * 60,
+            reconnect_nonce: 30,
+            ping_interval: 2 * 60,
+        }
+    }
+}
+
+/// 首次重连前的随机等待时长, 取 [0, nonce) 秒
+fn reconnect_jitter(reconnect_nonce: i32) -> Duration {
+    if reconnect_nonce <= 0 {
+        return Duration::ZERO;
+    }
+    let millis = rand::thread_rng().gen_range(0..reconnect_nonce as u64 * 1000);
+    Duration::from_millis(millis)
+}
+
 pub type WsResult<T> = Result<T, WsClientError>;
 
 #[derive(Debug, thiserror::Error)]
@@ -317,26 +862,127 @@ pub enum WsClientError {
     #[error("Client error: {code}, {message}")]
     ClientError { code: i32, message: String },
     #[error("WebSocket error: {0}")]
-    WsError(#[from] tokio_tungstenite::tungstenite::Error),
+    WsError(Box<tokio_tungstenite::tungstenite::Error>),
     #[error("Prost error: {0}")]
     ProstError(#[from] prost::DecodeError),
+    #[error("IO error: {0}")]
+    IOErr(#[from] std::io::Error),
+    #[error("Connect error: {0}")]
+    ConnectError(String),
+    #[error("Invalid frame: {0}")]
+    InvalidFrame(String),
+    #[error("Task error: {0}")]
+    TaskError(#[from] tokio::task::JoinError),
 }
 
-fn new_frame(service_id: i32) -> Frame {
-    let headers = vec![Header {
-        key: "type".to_string(),
-        value: "ping".to_string(),
-    }];
-
-    Frame {
-        seq_id: 0,
-        log_id: 0,
-        service: service_id,
-        method: 0,
-        headers,
-        payload_encoding: None,
-        payload_type: None,
-        payload: None,
-        log_id_new: None,
+impl WsClientError {
+    /// 应用凭证错误等无法通过重连恢复的错误
+    fn is_fatal(&self) -> bool {
+        matches!(self, WsClientError::ClientError { .. })
+    }
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for WsClientError {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        WsClientError::WsError(Box::new(err))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use log::LevelFilter;
+    use serde_json::json;
+    use tokio::sync::watch;
+
+    use crate::{
+        client::ws::{
+            reconnect_jitter, shutdown_signal, EndPointResponse, LarkWsClientBuilder, WsClientError,
+        },
+        core::{config::Config, constants::Domain},
+    };
+
+    #[test]
+    fn test_end_point_response() {
+        let resp: EndPointResponse = serde_json::from_value(json!({
+            "URL": "wss://msg-frontier.feishu.cn/ws/v2?device_id=1&service_id=2",
+            "ClientConfig": {
+                "ReconnectCount": 3,
+                "ReconnectInterval": 10,
+                "ReconnectNonce": 5,
+                "PingInterval": 90
+            }
+        }))
+        .unwrap();
+        let config = resp.client_config.unwrap();
+        assert_eq!(config.reconnect_count, 3);
+        assert_eq!(config.reconnect_interval, 10);
+        assert_eq!(config.reconnect_nonce, 5);
+        assert_eq!(config.ping_interval, 90);
+    }
+
+    #[test]
+    fn test_reconnect_jitter() {
+        assert!(reconnect_jitter(0).is_zero());
+        assert!(reconnect_jitter(-1).is_zero());
+        for _ in 0..100 {
+            assert!(reconnect_jitter(2).as_millis() < 2000);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_signal() {
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+        shutdown_tx.send(true).unwrap();
+        shutdown_signal(&mut shutdown_rx).await;
+
+        // 句柄被丢弃时不触发关闭
+        let (shutdown_tx, mut shutdown_rx) = watch::channel(false);
+        drop(shutdown_tx);
+        let result =
+            tokio::time::timeout(Duration::from_millis(20), shutdown_signal(&mut shutdown_rx))
+                .await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_builder_from_config() {
+        let config = Config {
+            app_id: "cli_a".to_string(),
+            app_secret: "secret".into(),
+            domain: Domain::Lark,
+            proxy: Some("socks5://127.0.0.1:1080".to_string()),
+            ..Default::default()
+        };
+        let client = LarkWsClientBuilder::from_config(&config)
+            .with_auto_reconnect(false)
+            .with_log_level(LevelFilter::Warn)
+            .build();
+
+        assert_eq!(client.app_id, "cli_a");
+        assert_eq!(client.app_secret.expose(), "secret");
+        assert_eq!(client.domain, Domain::Lark);
+        assert_eq!(
+            client.connect_options.proxy.as_deref(),
+            Some("socks5://127.0.0.1:1080")
+        );
+        assert!(!client.auto_reconnect);
+        assert_eq!(client.log_level, LevelFilter::Warn);
+        assert_eq!(client.locale, "zh");
+    }
+
+    #[test]
+    fn test_fatal_error() {
+        let client_error = WsClientError::ClientError {
+            code: 10003,
+            message: "invalid app_secret".to_string(),
+        };
+        assert!(client_error.is_fatal());
+        let server_error = WsClientError::ServerError {
+            code: 1,
+            message: "system busy".to_string(),
+        };
+        assert!(!server_error.is_fatal());
     }
 }
@@ -0,0 +1,330 @@
+//! pbbp2 帧的编解码
+//!
+//! 长连接上的消息都是 protobuf 编码的 [`Frame`], `method` 区分控制帧与数据帧,
+//! `headers` 中的 `type` 区分具体的消息类型。
+use std::{collections::HashMap, convert::Infallible, fmt::Display, str::FromStr};
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use lark_websocket_protobuf::pbbp2::{Frame, Header};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::client::ws::{ClientConfig, WsClientError, WsResult};
+
+/// 消息类型
+pub const HEADER_TYPE: &str = "type";
+/// 消息 ID, 拆包后继承
+pub const HEADER_MESSAGE_ID: &str = "message_id";
+/// 拆包数, 未拆包为 1
+pub const HEADER_SUM: &str = "sum";
+/// 包序号, 未拆包为 0
+pub const HEADER_SEQ: &str = "seq";
+/// 链路 ID
+pub const HEADER_TRACE_ID: &str = "trace_id";
+/// 业务处理耗时, 毫秒
+pub const HEADER_BIZ_RT: &str = "biz_rt";
+
+/// 帧类型, 对应 [`Frame::method`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameType {
+    /// 控制帧, 如 ping、pong
+    Control = 0,
+    /// 数据帧, 如事件、卡片回传交互
+    Data = 1,
+}
+
+impl TryFrom<i32> for FrameType {
+    type Error = WsClientError;
+
+    fn try_from(method: i32) -> Result<Self, Self::Error> {
+        match method {
+            0 => Ok(FrameType::Control),
+            1 => Ok(FrameType::Data),
+            _ => Err(WsClientError::InvalidFrame(format!(
+                "unknown frame method: {method}"
+            ))),
+        }
+    }
+}
+
+/// 消息类型, 对应 `type` 头
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageType {
+    Event,
+    Card,
+    Ping,
+    Pong,
+    /// 未知的消息类型, 数据帧直接回复确认
+    Unknown(String),
+}
+
+impl MessageType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            MessageType::Event => "event",
+            MessageType::Card => "card",
+            MessageType::Ping => "ping",
+            MessageType::Pong => "pong",
+            MessageType::Unknown(message_type) => message_type,
+        }
+    }
+}
+
+impl Display for MessageType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MessageType {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "event" => MessageType::Event,
+            "card" => MessageType::Card,
+            "ping" => MessageType::Ping,
+            "pong" => MessageType::Pong,
+            _ => MessageType::Unknown(s.to_string()),
+        })
+    }
+}
+
+/// [`Frame`] 的头部读取
+pub trait FrameExt {
+    /// 帧类型
+    fn frame_type(&self) -> WsResult<FrameType>;
+
+    /// 消息类型
+    fn message_type(&self) -> WsResult<MessageType>;
+
+    /// 读取头部, 不存在时返回 None
+    fn get_header(&self, key: &str) -> Option<&str>;
+
+    /// 读取必需的头部
+    fn header(&self, key: &str) -> WsResult<&str> {
+        self.get_header(key)
+            .ok_or_else(|| WsClientError::InvalidFrame(format!("missing header: {key}")))
+    }
+
+    /// 读取必需的头部并解析
+    fn parse_header<T: FromStr>(&self, key: &str) -> WsResult<T> {
+        let value = self.header(key)?;
+        value
+            .parse()
+            .map_err(|_| WsClientError::InvalidFrame(format!("invalid header {key}: {value}")))
+    }
+}
+
+impl FrameExt for Frame {
+    fn frame_type(&self) -> WsResult<FrameType> {
+        FrameType::try_from(self.method)
+    }
+
+    fn message_type(&self) -> WsResult<MessageType> {
+        self.parse_header(HEADER_TYPE)
+    }
+
+    fn get_header(&self, key: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|h| h.key == key)
+            .map(|h| h.value.as_str())
+    }
+}
+
+fn new_header(key: &str, value: impl ToString) -> Header {
+    Header {
+        key: key.to_string(),
+        value: value.to_string(),
+    }
+}
+
+/// 心跳帧
+pub fn new_ping_frame(service_id: i32) -> Frame {
+    Frame {
+        service: service_id,
+        method: FrameType::Control as i32,
+        headers: vec![new_header(HEADER_TYPE, MessageType::Ping)],
+        ..Default::default()
+    }
+}
+
+/// 心跳响应帧, payload 中带有服务端下发的连接配置
+pub fn new_pong_frame(service_id: i32, config: &ClientConfig) -> Frame {
+    Frame {
+        service: service_id,
+        method: FrameType::Control as i32,
+        headers: vec![new_header(HEADER_TYPE, MessageType::Pong)],
+        payload: serde_json::to_vec(config).ok(),
+        ..Default::default()
+    }
+}
+
+/// 数据帧, 拆包时每个分包使用相同的 message_id
+pub fn new_data_frame(
+    service_id: i32,
+    message_type: MessageType,
+    message_id: &str,
+    trace_id: &str,
+    sum: usize,
+    seq: usize,
+    payload: Vec<u8>,
+) -> Frame {
+    Frame {
+        service: service_id,
+        method: FrameType::Data as i32,
+        headers: vec![
+            new_header(HEADER_TYPE, message_type),
+            new_header(HEADER_MESSAGE_ID, message_id),
+            new_header(HEADER_SUM, sum),
+            new_header(HEADER_SEQ, seq),
+            new_header(HEADER_TRACE_ID, trace_id),
+        ],
+        payload: Some(payload),
+        ..Default::default()
+    }
+}
+
+/// 事件处理结果, 作为响应帧的 payload
+#[derive(Debug, Serialize)]
+struct ResponsePayload {
+    code: u16,
+    headers: HashMap<String, String>,
+    /// 处理函数的响应体, JSON 序列化后再做 base64 编码
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<String>,
+}
+
+/// 复用收到的数据帧构造响应帧, 追加处理耗时 biz_rt
+pub fn new_response_frame(mut frame: Frame, code: u16, data: Option<Value>, biz_rt: u128) -> Frame {
+    frame.headers.push(new_header(HEADER_BIZ_RT, biz_rt));
+    let payload = ResponsePayload {
+        code,
+        headers: HashMap::new(),
+        data: data.map(|data| BASE64_STANDARD.encode(data.to_string())),
+    };
+    frame.payload = Some(serde_json::to_vec(&payload).unwrap_or_default());
+
+    frame
+}
+
+/// 确认收到数据帧, 用于无需处理的消息
+pub fn new_ack_frame(frame: Frame) -> Frame {
+    new_response_frame(frame, 200, None, 0)
+}
+
+#[cfg(test)]
+mod test {
+    use base64::{prelude::BASE64_STANDARD, Engine};
+    use lark_websocket_protobuf::pbbp2::{Frame, Header};
+    use prost::Message;
+    use serde_json::{json, Value};
+
+    use crate::client::ws::{
+        codec::{
+            new_ack_frame, new_data_frame, new_ping_frame, new_pong_frame, new_response_frame,
+            FrameExt, FrameType, MessageType, HEADER_MESSAGE_ID, HEADER_SEQ, HEADER_SUM,
+        },
+        ClientConfig, WsClientError,
+    };
+
+    fn round_trip(frame: Frame) -> Frame {
+        Frame::decode(&*frame.encode_to_vec()).unwrap()
+    }
+
+    #[test]
+    fn test_ping_pong_frame() {
+        let frame = round_trip(new_ping_frame(7));
+        assert_eq!(frame.service, 7);
+        assert_eq!(frame.frame_type().unwrap(), FrameType::Control);
+        assert_eq!(frame.message_type().unwrap(), MessageType::Ping);
+        assert!(frame.payload.is_none());
+
+        let frame = round_trip(new_pong_frame(7, &ClientConfig::default()));
+        assert_eq!(frame.message_type().unwrap(), MessageType::Pong);
+        let config: ClientConfig = serde_json::from_slice(&frame.payload.unwrap()).unwrap();
+        assert_eq!(config.ping_interval, ClientConfig::default().ping_interval);
+    }
+
+    #[test]
+    fn test_data_frame() {
+        let frame = round_trip(new_data_frame(
+            7,
+            MessageType::Card,
+            "msg_1",
+            "trace_1",
+            2,
+            1,
+            b"{}".to_vec(),
+        ));
+        assert_eq!(frame.frame_type().unwrap(), FrameType::Data);
+        assert_eq!(frame.message_type().unwrap(), MessageType::Card);
+        assert_eq!(frame.header(HEADER_MESSAGE_ID).unwrap(), "msg_1");
+        assert_eq!(frame.parse_header::<usize>(HEADER_SUM).unwrap(), 2);
+        assert_eq!(frame.parse_header::<usize>(HEADER_SEQ).unwrap(), 1);
+        assert_eq!(frame.payload.as_deref(), Some(&b"{}"[..]));
+    }
+
+    #[test]
+    fn test_invalid_frame() {
+        let frame = Frame {
+            method: 9,
+            headers: vec![
+                Header {
+                    key: "type".to_string(),
+                    value: "unknown".to_string(),
+                },
+                Header {
+                    key: "sum".to_string(),
+                    value: "x".to_string(),
+                },
+            ],
+            ..Default::default()
+        };
+        assert!(matches!(
+            frame.frame_type(),
+            Err(WsClientError::InvalidFrame(_))
+        ));
+        assert_eq!(
+            frame.message_type().unwrap(),
+            MessageType::Unknown("unknown".to_string())
+        );
+        assert!(frame.header("message_id").is_err());
+        assert!(frame.parse_header::<usize>("sum").is_err());
+        assert!(frame.get_header("seq").is_none());
+    }
+
+    #[test]
+    fn test_response_frame() {
+        let frame = new_data_frame(3, MessageType::Event, "msg_1", "", 1, 0, b"{}".to_vec());
+        let frame = round_trip(new_response_frame(
+            frame,
+            200,
+            Some(json!({"toast": {"type": "info"}})),
+            12,
+        ));
+        assert_eq!(frame.service, 3);
+        assert_eq!(frame.header("message_id").unwrap(), "msg_1");
+        assert_eq!(frame.header("biz_rt").unwrap(), "12");
+
+        let payload: Value = serde_json::from_slice(&frame.payload.unwrap()).unwrap();
+        assert_eq!(payload["code"], 200);
+        assert_eq!(payload["headers"], json!({}));
+        let data = BASE64_STANDARD
+            .decode(payload["data"].as_str().unwrap())
+            .unwrap();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&data).unwrap(),
+            json!({"toast": {"type": "info"}})
+        );
+    }
+
+    #[test]
+    fn test_ack_frame() {
+        let frame = new_data_frame(3, MessageType::Event, "msg_1", "", 1, 0, b"{}".to_vec());
+        let frame = round_trip(new_ack_frame(frame));
+        let payload: Value = serde_json::from_slice(&frame.payload.unwrap()).unwrap();
+        assert_eq!(payload, json!({"code": 200, "headers": {}}));
+    }
+}
@@ -0,0 +1,152 @@
+use std::{io::Cursor, sync::Arc};
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+use tokio_socks::tcp::Socks5Stream;
+use tokio_tungstenite::{
+    client_async_tls_with_config,
+    tungstenite::{client::IntoClientRequest, http::HeaderValue},
+    Connector, MaybeTlsStream, WebSocketStream,
+};
+use url::Url;
+
+use crate::client::ws::{WsClientError, WsResult};
+
+pub(crate) type WsStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// 长连接的网络设置
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ConnectOptions {
+    /// 代理地址, 支持 http://、socks5:// 和 socks5h://
+    pub proxy: Option<String>,
+    /// 额外信任的根证书, PEM 格式
+    pub root_certificates: Vec<Vec<u8>>,
+    pub user_agent: String,
+}
+
+/// 建立 WebSocket 连接, 按需经过代理并使用自定义根证书
+pub(crate) async fn connect(url: &Url, options: &ConnectOptions) -> WsResult<WsStream> {
+    let host = url
+        .host_str()
+        .ok_or_else(|| WsClientError::ConnectError(format!("invalid url: {url}")))?
+        .to_string();
+    let port = url
+        .port_or_known_default()
+        .ok_or_else(|| WsClientError::ConnectError(format!("invalid url: {url}")))?;
+
+    let stream = match &options.proxy {
+        Some(proxy) => connect_via_proxy(proxy, &host, port).await?,
+        None => TcpStream::connect((host.as_str(), port)).await?,
+    };
+
+    let mut request = url.as_str().into_client_request()?;
+    if !options.user_agent.is_empty() {
+        if let Ok(user_agent) = HeaderValue::from_str(&options.user_agent) {
+            request.headers_mut().insert("User-Agent", user_agent);
+        }
+    }
+
+    let (ws_stream, _response) =
+        client_async_tls_with_config(request, stream, None, tls_connector(options)?).await?;
+
+    Ok(ws_stream)
+}
+
+async fn connect_via_proxy(proxy: &str, host: &str, port: u16) -> WsResult<TcpStream> {
+    let proxy_url = Url::parse(proxy)?;
+    let proxy_host = proxy_url
+        .host_str()
+        .ok_or_else(|| WsClientError::ConnectError(format!("invalid proxy: {proxy}")))?;
+    let proxy_port = proxy_url
+        .port_or_known_default()
+        .unwrap_or(match proxy_url.scheme() {
+            "http" => 80,
+            _ => 1080,
+        });
+    let username = proxy_url.username();
+    let password = proxy_url.password().unwrap_or_default();
+
+    match proxy_url.scheme() {
+        "http" => {
+            let mut stream = TcpStream::connect((proxy_host, proxy_port)).await?;
+            let mut connect_req =
+                format!("CONNECT {host}:{port} HTTP/1.1\r\nHost: {host}:{port}\r\n");
+            if !username.is_empty() {
+                let credentials = BASE64_STANDARD.encode(format!("{username}:{password}"));
+                connect_req += &format!("Proxy-Authorization: Basic {credentials}\r\n");
+            }
+            connect_req += "\r\n";
+            stream.write_all(connect_req.as_bytes()).await?;
+
+            // 读取代理响应头, 直到空行
+            let mut response = Vec::new();
+            let mut buf = [0u8; 1];
+            while !response.ends_with(b"\r\n\r\n") {
+                if stream.read(&mut buf).await? == 0 {
+                    return Err(WsClientError::ConnectError(
+                        "proxy closed connection".to_string(),
+                    ));
+                }
+                response.push(buf[0]);
+                if response.len() > 8192 {
+                    return Err(WsClientError::ConnectError(
+                        "proxy response too large".to_string(),
+                    ));
+                }
+            }
+            let status_line = String::from_utf8_lossy(&response);
+            let status_line = status_line.lines().next().unwrap_or_default();
+            if status_line.split_whitespace().nth(1) != Some("200") {
+                return Err(WsClientError::ConnectError(format!(
+                    "proxy CONNECT failed: {status_line}"
+                )));
+            }
+
+            Ok(stream)
+        }
+        "socks5" | "socks5h" => {
+            let proxy_addr = (proxy_host, proxy_port);
+            let stream = if username.is_empty() {
+                Socks5Stream::connect(proxy_addr, (host, port)).await
+            } else {
+                Socks5Stream::connect_with_password(proxy_addr, (host, port), username, password)
+                    .await
+            }
+            .map_err(|e| WsClientError::ConnectError(format!("socks5 proxy error: {e}")))?;
+
+            Ok(stream.into_inner())
+        }
+        scheme => Err(WsClientError::ConnectError(format!(
+            "unsupported proxy scheme: {scheme}"
+        ))),
+    }
+}
+
+/// 有额外根证书时, 在系统根证书的基础上追加
+fn tls_connector(options: &ConnectOptions) -> WsResult<Option<Connector>> {
+    if options.root_certificates.is_empty() {
+        return Ok(None);
+    }
+
+    let mut roots = rustls::RootCertStore::empty();
+    for cert in rustls_native_certs::load_native_certs()? {
+        // 忽略系统中无法解析的证书
+        roots.add(cert).ok();
+    }
+    for pem in &options.root_certificates {
+        for cert in rustls_pemfile::certs(&mut Cursor::new(pem)) {
+            roots
+                .add(cert?)
+                .map_err(|e| WsClientError::ConnectError(format!("invalid certificate: {e}")))?;
+        }
+    }
+
+    let config = rustls::ClientConfig::builder()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+    Ok(Some(Connector::Rustls(Arc::new(config))))
+}
@@ -0,0 +1,183 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use log::LevelFilter;
+
+/// 拆包消息的默认等待时长, 超时后丢弃未收齐的消息
+pub(crate) const DEFAULT_FRAME_TIMEOUT: Duration = Duration::from_secs(5);
+/// 单条消息的最大拆包数, 超出时视为非法帧
+pub(crate) const MAX_FRAMES: usize = 64;
+
+/// 拆包消息的重组缓冲区, 按 message_id 收集各分包
+#[derive(Debug, Clone)]
+pub(crate) struct FrameBuffer {
+    timeout: Duration,
+    messages: HashMap<String, PartialMessage>,
+    log_level: LevelFilter,
+}
+
+#[derive(Debug, Clone)]
+struct PartialMessage {
+    parts: Vec<Option<Vec<u8>>>,
+    received: usize,
+    created_at: Instant,
+}
+
+impl Default for FrameBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_FRAME_TIMEOUT, LevelFilter::Trace)
+    }
+}
+
+impl FrameBuffer {
+    pub fn new(timeout: Duration, log_level: LevelFilter) -> Self {
+        Self {
+            timeout,
+            messages: HashMap::new(),
+            log_level,
+        }
+    }
+
+    /// 放入一个分包, 收齐后按 seq 顺序拼接并返回完整的 payload
+    ///
+    /// - sum: 拆包数
+    /// - seq: 包序号, 从 0 开始
+    pub fn push(
+        &mut self,
+        message_id: &str,
+        sum: usize,
+        seq: usize,
+        payload: Vec<u8>,
+    ) -> Option<Vec<u8>> {
+        self.evict_expired();
+
+        if sum == 0 || sum > MAX_FRAMES {
+            ws_log!(
+                self.log_level,
+                Warn,
+                "invalid frame sum {sum} for message {message_id}"
+            );
+            self.messages.remove(message_id);
+            return None;
+        }
+        if sum == 1 {
+            return Some(payload);
+        }
+        if seq >= sum {
+            ws_log!(
+                self.log_level,
+                Warn,
+                "invalid frame seq {seq} for message {message_id} with sum {sum}"
+            );
+            return None;
+        }
+
+        let message = self
+            .messages
+            .entry(message_id.to_string())
+            .or_insert_with(|| PartialMessage {
+                parts: vec![None; sum],
+                received: 0,
+                created_at: Instant::now(),
+            });
+        // 拆包数不一致时以最新的为准
+        if message.parts.len() != sum {
+            *message = PartialMessage {
+                parts: vec![None; sum],
+                received: 0,
+                created_at: Instant::now(),
+            };
+        }
+        if message.parts[seq].is_none() {
+            message.received += 1;
+        }
+        message.parts[seq] = Some(payload);
+
+        if message.received < sum {
+            return None;
+        }
+
+        let message = self.messages.remove(message_id)?;
+        Some(message.parts.into_iter().flatten().flatten().collect())
+    }
+
+    fn evict_expired(&mut self) {
+        let timeout = self.timeout;
+        let log_level = self.log_level;
+        self.messages.retain(|message_id, message| {
+            let expired = message.created_at.elapsed() >= timeout;
+            if expired {
+                ws_log!(
+                    log_level,
+                    Warn,
+                    "drop incomplete message {message_id}, received {}/{} frames",
+                    message.received,
+                    message.parts.len()
+                );
+            }
+            !expired
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use log::LevelFilter;
+
+    use crate::client::ws::frame_buffer::{FrameBuffer, MAX_FRAMES};
+
+    #[test]
+    fn test_out_of_order_frames() {
+        let mut buffer = FrameBuffer::default();
+        assert_eq!(buffer.push("msg_1", 3, 2, b"ld\"}".to_vec()), None);
+        assert_eq!(buffer.push("msg_2", 2, 1, b"b".to_vec()), None);
+        assert_eq!(buffer.push("msg_1", 3, 0, b"{\"hello\":".to_vec()), None);
+        // 重复的分包不影响计数
+        assert_eq!(buffer.push("msg_1", 3, 0, b"{\"hello\":".to_vec()), None);
+        assert_eq!(
+            buffer.push("msg_1", 3, 1, b"\"wor".to_vec()),
+            Some(br#"{"hello":"world"}"#.to_vec())
+        );
+        assert_eq!(buffer.messages.len(), 1);
+        assert_eq!(
+            buffer.push("msg_2", 2, 0, b"a".to_vec()),
+            Some(b"ab".to_vec())
+        );
+        assert_eq!(buffer.messages.len(), 0);
+    }
+
+    #[test]
+    fn test_single_frame() {
+        let mut buffer = FrameBuffer::default();
+        assert_eq!(
+            buffer.push("msg_1", 1, 0, b"{}".to_vec()),
+            Some(b"{}".to_vec())
+        );
+        assert_eq!(buffer.push("msg_2", 2, 5, b"{}".to_vec()), None);
+        assert_eq!(buffer.messages.len(), 0);
+    }
+
+    #[test]
+    fn test_invalid_sum() {
+        let mut buffer = FrameBuffer::default();
+        assert_eq!(buffer.push("msg_1", 0, 0, b"{}".to_vec()), None);
+        assert_eq!(buffer.push("msg_2", 2, 0, b"a".to_vec()), None);
+        // 非法的拆包数丢弃未收齐的消息
+        assert_eq!(buffer.push("msg_2", MAX_FRAMES + 1, 1, b"b".to_vec()), None);
+        assert_eq!(buffer.push("msg_3", usize::MAX, 0, b"c".to_vec()), None);
+        assert_eq!(buffer.messages.len(), 0);
+    }
+
+    #[test]
+    fn test_expired_frames() {
+        let mut buffer = FrameBuffer::new(Duration::from_millis(10), LevelFilter::Trace);
+        assert_eq!(buffer.push("msg_1", 2, 0, b"a".to_vec()), None);
+        std::thread::sleep(Duration::from_millis(20));
+        assert_eq!(buffer.push("msg_1", 2, 1, b"b".to_vec()), None);
+        assert_eq!(buffer.messages.len(), 1);
+    }
+}
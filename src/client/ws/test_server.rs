@@ -0,0 +1,512 @@
+//! 长连接服务的本地替身, crate 外使用时需要开启 `test-util` feature
+//!
+//! 提供获取连接地址的 HTTP 接口和收发 pbbp2 帧的 WebSocket 接口, 用于在不连接飞书的情况下
+//! 测试 [`LarkWsClient`](crate::client::ws::LarkWsClient): 推送事件、检查 ACK、下发连接配置、
+//! 断开连接以触发重连。
+//!
+//! ```ignore
+//! let server = FakeWsServer::start().await?;
+//! let handle = LarkWsClient::builder("app_id", "app_secret")
+//!     .with_domain(server.domain())
+//!     .with_event_dispatcher(dispatcher)
+//!     .build()
+//!     .start()
+//!     .await?;
+//! server.wait_for_connections(1).await;
+//!
+//! let message_id = server.push_event(&json!({"schema": "2.0", ...}));
+//! let ack = server.recv_response().await.unwrap();
+//! ```
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex as StdMutex,
+    },
+};
+
+use futures_util::{SinkExt, StreamExt};
+use lark_websocket_protobuf::pbbp2::Frame;
+use log::{debug, error};
+use prost::Message as ProstMessage;
+use serde_json::{json, Value};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::{mpsc, watch, Mutex},
+};
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+use crate::{
+    client::ws::{
+        codec::{new_data_frame, new_pong_frame, FrameExt, FrameType, MessageType},
+        ClientConfig, END_POINT_URL,
+    },
+    core::{
+        constants::Domain,
+        test_http::{read_request, write_json},
+    },
+};
+
+/// 数据帧使用的服务 ID
+const SERVICE_ID: i32 = 1;
+
+/// 长连接服务的本地替身
+#[derive(Debug)]
+pub struct FakeWsServer {
+    http_addr: SocketAddr,
+    state: Arc<ServerState>,
+    /// 客户端发来的数据帧, 即事件的响应帧
+    responses: Mutex<mpsc::UnboundedReceiver<Frame>>,
+    connections: watch::Receiver<usize>,
+}
+
+#[derive(Debug)]
+struct ServerState {
+    ws_addr: SocketAddr,
+    client_config: StdMutex<ClientConfig>,
+    /// 获取连接地址时返回的错误码和错误信息
+    endpoint_error: StdMutex<Option<(i32, String)>>,
+    /// 当前连接的发送端, 丢弃后连接断开
+    sender: StdMutex<Option<mpsc::UnboundedSender<Message>>>,
+    responses: mpsc::UnboundedSender<Frame>,
+    connections: watch::Sender<usize>,
+    endpoint_requests: AtomicUsize,
+    pings: AtomicUsize,
+    message_seq: AtomicUsize,
+}
+
+impl FakeWsServer {
+    /// 在本地随机端口上启动服务
+    pub async fn start() -> std::io::Result<Self> {
+        let http_listener = TcpListener::bind("127.0.0.1:0").await?;
+        let ws_listener = TcpListener::bind("127.0.0.1:0").await?;
+        let (responses_tx, responses_rx) = mpsc::unbounded_channel();
+        let (connections_tx, connections_rx) = watch::channel(0);
+
+        let state = Arc::new(ServerState {
+            ws_addr: ws_listener.local_addr()?,
+            // 测试中立即重连, 不做随机等待
+            client_config: StdMutex::new(ClientConfig::new(-1, 0, 0, 2 * 60)),
+            endpoint_error: StdMutex::new(None),
+            sender: StdMutex::new(None),
+            responses: responses_tx,
+            connections: connections_tx,
+            endpoint_requests: AtomicUsize::new(0),
+            pings: AtomicUsize::new(0),
+            message_seq: AtomicUsize::new(0),
+        });
+
+        let server = Self {
+            http_addr: http_listener.local_addr()?,
+            state: Arc::clone(&state),
+            responses: Mutex::new(responses_rx),
+            connections: connections_rx,
+        };
+
+        let http_state = Arc::clone(&state);
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = http_listener.accept().await {
+                tokio::spawn(serve_endpoint(stream, Arc::clone(&http_state)));
+            }
+        });
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = ws_listener.accept().await {
+                tokio::spawn(serve_ws(stream, Arc::clone(&state)));
+            }
+        });
+
+        Ok(server)
+    }
+
+    /// 指向本服务的域名, 传给 [`LarkWsClient::with_domain`](crate::client::ws::LarkWsClient::with_domain)
+    pub fn domain(&self) -> Domain {
+        Domain::Custom(format!("http://{}", self.http_addr))
+    }
+
+    /// 设置获取连接地址时下发的连接配置
+    pub fn set_client_config(&self, config: ClientConfig) {
+        *self.state.client_config.lock().unwrap() = config;
+    }
+
+    /// 获取连接地址时返回错误, 错误码为 1 时客户端视为服务端繁忙并继续重连
+    pub fn fail_endpoint(&self, code: i32, msg: impl ToString) {
+        *self.state.endpoint_error.lock().unwrap() = Some((code, msg.to_string()));
+    }
+
+    /// 恢复正常返回连接地址
+    pub fn recover_endpoint(&self) {
+        *self.state.endpoint_error.lock().unwrap() = None;
+    }
+
+    /// 累计建立的连接数
+    pub fn connection_count(&self) -> usize {
+        *self.connections.borrow()
+    }
+
+    /// 等待累计建立的连接数达到 count
+    pub async fn wait_for_connections(&self, count: usize) {
+        let mut connections = self.connections.clone();
+        let _ = connections.wait_for(|n| *n >= count).await;
+    }
+
+    /// 获取连接地址的请求数
+    pub fn endpoint_requests(&self) -> usize {
+        self.state.endpoint_requests.load(Ordering::SeqCst)
+    }
+
+    /// 收到的心跳帧数
+    pub fn ping_count(&self) -> usize {
+        self.state.pings.load(Ordering::SeqCst)
+    }
+
+    /// 推送事件, 返回消息 ID
+    pub fn push_event(&self, event: &Value) -> String {
+        self.push_multi_frame(MessageType::Event, event, 1)
+    }
+
+    /// 推送卡片回传交互, 返回消息 ID
+    pub fn push_card(&self, event: &Value) -> String {
+        self.push_multi_frame(MessageType::Card, event, 1)
+    }
+
+    /// 将消息拆成 parts 个分包后倒序推送, 返回消息 ID
+    pub fn push_multi_frame(
+        &self,
+        message_type: MessageType,
+        event: &Value,
+        parts: usize,
+    ) -> String {
+        let seq = self.state.message_seq.fetch_add(1, Ordering::SeqCst);
+        let message_id = format!("msg_{seq}");
+        let payload = event.to_string().into_bytes();
+        let parts = parts.clamp(1, payload.len().max(1));
+        let chunk_size = payload.len().div_ceil(parts);
+
+        let frames: Vec<_> = payload
+            .chunks(chunk_size.max(1))
+            .enumerate()
+            .map(|(seq, chunk)| {
+                new_data_frame(
+                    SERVICE_ID,
+                    message_type.clone(),
+                    &message_id,
+                    &format!("trace_{seq}"),
+                    parts,
+                    seq,
+                    chunk.to_vec(),
+                )
+            })
+            .collect();
+        for frame in frames.into_iter().rev() {
+            self.send_frame(frame);
+        }
+
+        message_id
+    }
+
+    /// 发送心跳响应帧, 下发新的连接配置
+    pub fn send_pong(&self, config: &ClientConfig) {
+        self.send_frame(new_pong_frame(SERVICE_ID, config));
+    }
+
+    /// 发送任意帧
+    pub fn send_frame(&self, frame: Frame) {
+        match self.state.sender.lock().unwrap().as_ref() {
+            Some(sender) => {
+                let _ = sender.send(Message::Binary(frame.encode_to_vec()));
+            }
+            None => error!("No active connection"),
+        }
+    }
+
+    /// 不发送关闭帧直接断开当前连接
+    pub fn drop_connection(&self) {
+        self.state.sender.lock().unwrap().take();
+    }
+
+    /// 等待客户端发来的下一个响应帧, 服务关闭时返回 None
+    pub async fn recv_response(&self) -> Option<Frame> {
+        self.responses.lock().await.recv().await
+    }
+}
+
+/// 获取连接地址的 HTTP 接口, 只处理单个请求
+async fn serve_endpoint(mut stream: TcpStream, state: Arc<ServerState>) {
+    let Some(request) = read_request(&mut stream).await else {
+        return;
+    };
+    // 只检查请求体中带有应用凭证, 不校验其内容
+    let has_app_id =
+        serde_json::from_slice::<Value>(&request.body).is_ok_and(|body| body["AppID"].is_string());
+    let status = if request.method != "POST" || request.path != END_POINT_URL {
+        "404 Not Found"
+    } else if !has_app_id {
+        "400 Bad Request"
+    } else {
+        "200 OK"
+    };
+    let n = state.endpoint_requests.fetch_add(1, Ordering::SeqCst);
+
+    let body = match state.endpoint_error.lock().unwrap().clone() {
+        Some((code, msg)) => json!({"code": code, "msg": msg}),
+        None => json!({
+            "code": 0,
+            "msg": "ok",
+            "data": {
+                "URL": format!(
+                    "ws://{}/ws?device_id=device_{n}&service_id={SERVICE_ID}",
+                    state.ws_addr
+                ),
+                "ClientConfig": *state.client_config.lock().unwrap(),
+            }
+        }),
+    };
+
+    let _ = write_json(&mut stream, status, &body).await;
+}
+
+/// WebSocket 接口, 同一时间只保留最新的连接
+async fn serve_ws(stream: TcpStream, state: Arc<ServerState>) {
+    let ws_stream = match tokio_tungstenite::accept_async(stream).await {
+        Ok(ws_stream) => ws_stream,
+        Err(e) => {
+            error!("Failed to accept: {:?}", e);
+            return;
+        }
+    };
+    let (mut write, mut read) = ws_stream.split();
+    let (sender_tx, mut sender_rx) = mpsc::unbounded_channel();
+    *state.sender.lock().unwrap() = Some(sender_tx);
+    state.connections.send_modify(|n| *n += 1);
+
+    loop {
+        tokio::select! {
+            msg = sender_rx.recv() => match msg {
+                Some(msg) => {
+                    if write.send(msg).await.is_err() {
+                        break;
+                    }
+                }
+                // 发送端被丢弃, 断开连接
+                None => break,
+            },
+            msg = read.next() => match msg {
+                Some(Ok(Message::Binary(bin))) => {
+                    let Ok(frame) = Frame::decode(&*bin) else {
+                        continue;
+                    };
+                    match frame.frame_type() {
+                        Ok(FrameType::Control) => {
+                            if frame.message_type().ok() == Some(MessageType::Ping) {
+                                state.pings.fetch_add(1, Ordering::SeqCst);
+                            }
+                        }
+                        Ok(FrameType::Data) => {
+                            let _ = state.responses.send(frame);
+                        }
+                        Err(e) => debug!("Invalid frame: {:?}", e),
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use serde_json::{json, Value};
+
+    use crate::{
+        client::ws::{
+            codec::{FrameExt, MessageType},
+            test_server::FakeWsServer,
+            ClientConfig, ConnectionState, LarkWsClient, WsClientError,
+        },
+        event::{EventDispatcher, EventV2},
+    };
+
+    const TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn event(event_id: &str) -> Value {
+        json!({
+            "schema": "2.0",
+            "header": {"event_id": event_id, "event_type": "test.event"},
+            "event": {"text": "x".repeat(64)}
+        })
+    }
+
+    fn dispatcher(count: Arc<AtomicUsize>) -> EventDispatcher {
+        EventDispatcher::new().register("test.event", move |_event: EventV2<Value>| {
+            let count = Arc::clone(&count);
+            async move {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                count.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        })
+    }
+
+    async fn ack_code(server: &FakeWsServer, message_id: &str) -> i64 {
+        let frame = tokio::time::timeout(TIMEOUT, server.recv_response())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(frame.header("message_id").unwrap(), message_id);
+        assert!(frame.get_header("biz_rt").is_some());
+        let payload: Value = serde_json::from_slice(&frame.payload.unwrap()).unwrap();
+        payload["code"].as_i64().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_push_event_and_multi_frame() {
+        let server = FakeWsServer::start().await.unwrap();
+        let count = Arc::new(AtomicUsize::new(0));
+        let handle = LarkWsClient::builder("app_id", "app_secret")
+            .with_domain(server.domain())
+            .with_event_dispatcher(dispatcher(Arc::clone(&count)))
+            .build()
+            .start()
+            .await
+            .unwrap();
+        server.wait_for_connections(1).await;
+        assert_eq!(handle.state(), ConnectionState::Connected);
+
+        let message_id = server.push_event(&event("event_1"));
+        assert_eq!(ack_code(&server, &message_id).await, 200);
+
+        let message_id = server.push_multi_frame(MessageType::Event, &event("event_2"), 3);
+        assert_eq!(ack_code(&server, &message_id).await, 200);
+        assert_eq!(count.load(Ordering::SeqCst), 2);
+        // 连接建立后立即发送心跳
+        assert!(server.ping_count() >= 1);
+
+        // 未注册的事件类型也回复 200, 避免重推
+        let message_id = server.push_card(&json!({
+            "schema": "2.0",
+            "header": {"event_id": "event_3", "event_type": "card.action.trigger"},
+            "event": {}
+        }));
+        assert_eq!(ack_code(&server, &message_id).await, 200);
+
+        // 未知的消息类型直接回复确认
+        let message_id = server.push_multi_frame(
+            MessageType::Unknown("unknown_type".to_string()),
+            &json!({}),
+            1,
+        );
+        assert_eq!(ack_code(&server, &message_id).await, 200);
+
+        // 无法解析的事件回复 500
+        let message_id = server.push_event(&json!({"schema": "2.0", "header": {}}));
+        assert_eq!(ack_code(&server, &message_id).await, 500);
+
+        handle.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_reconnect_after_drop() {
+        let server = FakeWsServer::start().await.unwrap();
+        let count = Arc::new(AtomicUsize::new(0));
+        let handle = LarkWsClient::builder("app_id", "app_secret")
+            .with_domain(server.domain())
+            .with_event_dispatcher(dispatcher(Arc::clone(&count)))
+            .build()
+            .start()
+            .await
+            .unwrap();
+        server.wait_for_connections(1).await;
+
+        server.drop_connection();
+        tokio::time::timeout(TIMEOUT, server.wait_for_connections(2))
+            .await
+            .unwrap();
+        assert_eq!(server.endpoint_requests(), 2);
+
+        let mut state = handle.subscribe();
+        tokio::time::timeout(
+            TIMEOUT,
+            state.wait_for(|state| *state == ConnectionState::Connected),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        let message_id = server.push_event(&event("event_1"));
+        assert_eq!(ack_code(&server, &message_id).await, 200);
+
+        handle.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_pong_config_limits_reconnect() {
+        let server = FakeWsServer::start().await.unwrap();
+        let handle = LarkWsClient::builder("app_id", "app_secret")
+            .with_domain(server.domain())
+            .build()
+            .start()
+            .await
+            .unwrap();
+        server.wait_for_connections(1).await;
+
+        // 下发的配置只允许重连 2 次, 之后连接地址一直不可用
+        server.send_pong(&ClientConfig::new(2, 0, 0, 2 * 60));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        server.fail_endpoint(1, "system busy");
+        server.drop_connection();
+
+        let result = tokio::time::timeout(TIMEOUT, handle.join()).await.unwrap();
+        assert!(matches!(result, Err(WsClientError::ConnectError(_))));
+        assert_eq!(server.endpoint_requests(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_builder_ping_interval() {
+        let server = FakeWsServer::start().await.unwrap();
+        let handle = LarkWsClient::builder("app_id", "app_secret")
+            .with_domain(server.domain())
+            .with_ping_interval(Duration::from_millis(50))
+            .with_locale("en")
+            .build()
+            .start()
+            .await
+            .unwrap();
+        server.wait_for_connections(1).await;
+
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert!(server.ping_count() >= 3);
+        handle.shutdown().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drains_handlers() {
+        let server = FakeWsServer::start().await.unwrap();
+        let count = Arc::new(AtomicUsize::new(0));
+        let handle = LarkWsClient::builder("app_id", "app_secret")
+            .with_domain(server.domain())
+            .with_event_dispatcher(dispatcher(Arc::clone(&count)))
+            .build()
+            .start()
+            .await
+            .unwrap();
+        server.wait_for_connections(1).await;
+
+        let message_id = server.push_event(&event("event_1"));
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        let state = handle.subscribe();
+        handle.shutdown().await.unwrap();
+
+        assert_eq!(count.load(Ordering::SeqCst), 1);
+        assert_eq!(*state.borrow(), ConnectionState::Closed);
+        assert_eq!(ack_code(&server, &message_id).await, 200);
+        assert_eq!(server.connection_count(), 1);
+    }
+}
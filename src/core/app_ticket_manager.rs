@@ -3,14 +3,13 @@ use std::time::Duration;
 use async_recursion::async_recursion;
 use lazy_static::lazy_static;
 use reqwest::Method;
-use serde::{Deserialize, Serialize};
 
 use crate::core::{
     api_req::ApiRequest,
     api_resp::{BaseResponse, RawResponse},
     cache::{Cache, LocalCache},
     config::Config,
-    constants::{AccessTokenType, APP_TICKET_KEY_PREFIX, APPLY_APP_TICKET_PATH},
+    constants::{AccessTokenType, APPLY_APP_TICKET_PATH, APP_TICKET_KEY_PREFIX},
     http::Transport,
     SDKResult,
 };
@@ -77,12 +76,6 @@ pub async fn apply_app_ticket(config: &Config) -> SDKResult<()> {
     Ok(())
 }
 
-#[derive(Serialize, Deserialize)]
-struct ResendAppTicketReq {
-    app_id: String,
-    app_secret: String,
-}
-
 // #[derive(Serialize, Deserialize)]
 // struct ResendAppTicketResp {
 //     #[serde(skip)]
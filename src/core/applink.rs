@@ -0,0 +1,84 @@
+use url::Url;
+
+use crate::core::constants::Domain;
+
+/// AppLink 协议链接
+///
+/// 用于在飞书/Lark 客户端内打开机器人会话、群聊、网页应用、小程序等。
+///
+/// [AppLink 协议](https://open.feishu.cn/document/uAjLw4CM/uYjL24iN/applink-protocol/supported-protocol)
+#[derive(Debug, Clone)]
+pub struct AppLink {
+    base_url: String,
+    path: String,
+    query: Vec<(String, String)>,
+}
+
+impl AppLink {
+    pub fn new(domain: &Domain, path: &str) -> Self {
+        Self {
+            base_url: domain.applink_base_url().to_string(),
+            path: path.to_string(),
+            query: vec![],
+        }
+    }
+
+    /// 打开机器人会话
+    pub fn bot(domain: &Domain, app_id: &str) -> Self {
+        Self::new(domain, "/client/bot/open").query("appId", app_id)
+    }
+
+    /// 打开群聊
+    pub fn chat(domain: &Domain, open_chat_id: &str) -> Self {
+        Self::new(domain, "/client/chat/open").query("openChatId", open_chat_id)
+    }
+
+    /// 打开与某个用户的单聊
+    pub fn user_chat(domain: &Domain, open_id: &str) -> Self {
+        Self::new(domain, "/client/chat/open").query("openId", open_id)
+    }
+
+    /// 打开网页应用
+    pub fn web_app(domain: &Domain, app_id: &str) -> Self {
+        Self::new(domain, "/client/web_app/open").query("appId", app_id)
+    }
+
+    /// 打开小程序
+    pub fn mini_program(domain: &Domain, app_id: &str) -> Self {
+        Self::new(domain, "/client/mini_program/open").query("appId", app_id)
+    }
+
+    /// 追加查询参数
+    pub fn query(mut self, key: impl ToString, value: impl ToString) -> Self {
+        self.query.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn build(self) -> String {
+        let base = format!("{}{}", self.base_url, self.path);
+        match Url::parse_with_params(&base, &self.query) {
+            Ok(url) => url.to_string(),
+            Err(_) => base,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::{applink::AppLink, constants::Domain};
+
+    #[test]
+    fn test_applink_domain() {
+        let link = AppLink::bot(&Domain::Feishu, "cli_9b445f5258795107").build();
+        assert_eq!(
+            link,
+            "https://applink.feishu.cn/client/bot/open?appId=cli_9b445f5258795107"
+        );
+
+        let link = AppLink::chat(&Domain::Lark, "oc_41e7bdf4877cfc316136f4ccf6c32613").build();
+        assert_eq!(
+            link,
+            "https://applink.larksuite.com/client/chat/open?openChatId=oc_41e7bdf4877cfc316136f4ccf6c32613"
+        );
+    }
+}
@@ -1,13 +1,21 @@
 use std::{collections::HashMap, time::Duration};
 
-use crate::core::constants::{AppType, FEISHU_BASE_URL};
+use url::Url;
+
+use crate::core::{
+    constants::{AppType, Domain},
+    error::LarkAPIError,
+    secret::Secret,
+    utils::user_agent,
+    SDKResult,
+};
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub app_id: String,
-    pub app_secret: String,
-    /// 域名, 默认为 https://open.feishu.cn
-    pub base_url: String,
+    pub app_secret: Secret,
+    /// 域名, 默认为飞书 https://open.feishu.cn
+    pub domain: Domain,
     pub enable_token_cache: bool,
     /// 客户端超时时间, 单位秒, 默认永不超时
     pub timeout: Option<f32>,
@@ -16,20 +24,88 @@ pub struct Config {
     pub http_client: reqwest::Client,
     pub req_timeout: Option<Duration>,
     pub header: HashMap<String, String>,
+    /// 代理地址, 支持 http://、socks5:// 和 socks5h://, 可带用户名密码
+    pub proxy: Option<String>,
+    /// 额外信任的根证书, PEM 格式
+    pub root_certificates: Vec<Vec<u8>>,
+    /// 追加在 User-Agent 之后的标识
+    pub user_agent_suffix: Option<String>,
+    /// 事件订阅的 Encrypt Key
+    pub encrypt_key: Option<Secret>,
+    /// 事件订阅的 Verification Token
+    pub verification_token: Option<Secret>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         Self {
             app_id: "".to_string(),
-            app_secret: "".to_string(),
-            base_url: FEISHU_BASE_URL.to_string(),
+            app_secret: Secret::default(),
+            domain: Domain::Feishu,
             enable_token_cache: true,
             timeout: None,
             app_type: AppType::SelfBuild,
             http_client: reqwest::Client::new(),
             req_timeout: None,
             header: Default::default(),
+            proxy: None,
+            root_certificates: vec![],
+            user_agent_suffix: None,
+            encrypt_key: None,
+            verification_token: None,
+        }
+    }
+}
+
+impl Config {
+    /// 开放接口地址
+    ///
+    /// `base_url` 字段已由 `domain` 取代, 保留该方法以便迁移
+    #[deprecated(note = "use `config.domain.open_base_url()` instead")]
+    pub fn base_url(&self) -> &str {
+        self.domain.open_base_url()
+    }
+
+    /// 请求使用的 User-Agent
+    pub fn user_agent(&self) -> String {
+        match &self.user_agent_suffix {
+            Some(suffix) if !suffix.is_empty() => format!("{} {}", user_agent(), suffix),
+            _ => user_agent(),
+        }
+    }
+
+    /// 按照超时、代理、根证书和 User-Agent 设置创建 HTTP 客户端
+    pub fn build_http_client(&self) -> SDKResult<reqwest::Client> {
+        let mut builder = reqwest::Client::builder().user_agent(self.user_agent());
+        if let Some(req_timeout) = self.req_timeout {
+            builder = builder.timeout(req_timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            check_proxy(proxy)?;
+            builder = builder.proxy(reqwest::Proxy::all(proxy)?);
+        }
+        for pem in &self.root_certificates {
+            builder = builder.add_root_certificate(reqwest::Certificate::from_pem(pem)?);
         }
+
+        Ok(builder.build()?)
     }
 }
+
+/// 代理支持的协议, 长连接客户端与 REST 请求一致
+pub const PROXY_SCHEMES: [&str; 3] = ["http", "socks5", "socks5h"];
+
+/// 校验代理地址及协议
+pub(crate) fn check_proxy(proxy: &str) -> SDKResult<()> {
+    let url = Url::parse(proxy)
+        .map_err(|e| LarkAPIError::ConfigError(format!("invalid proxy {proxy}: {e}")))?;
+    if !PROXY_SCHEMES.contains(&url.scheme()) {
+        return Err(LarkAPIError::ConfigError(format!(
+            "unsupported proxy scheme: {}, expected one of {}",
+            url.scheme(),
+            PROXY_SCHEMES.join(", ")
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,405 @@
+//! 从环境变量或配置文件加载客户端配置
+//!
+//! 支持 TOML、JSON 以及 `.env` 格式。单应用配置直接写在顶层:
+//!
+//! ```toml
+//! app_id = "cli_a1b2c3"
+//! app_secret = "xxx"
+//! domain = "lark"
+//! req_timeout = 10
+//! ```
+//!
+//! 多应用配置写在 `apps` 下, 顶层字段作为所有应用的默认值:
+//!
+//! ```toml
+//! domain = "feishu"
+//!
+//! [apps.alert]
+//! app_id = "cli_a1b2c3"
+//! app_secret = "xxx"
+//!
+//! [apps.ops]
+//! app_id = "cli_d4e5f6"
+//! app_secret = "yyy"
+//! app_type = "marketplace"
+//! ```
+use std::{collections::HashMap, fs, path::Path, time::Duration};
+
+use serde::Deserialize;
+
+use crate::core::{
+    config::Config,
+    constants::{AppType, Domain},
+    error::LarkAPIError,
+    secret::Secret,
+    SDKResult,
+};
+
+pub const ENV_APP_ID: &str = "LARK_APP_ID";
+pub const ENV_APP_SECRET: &str = "LARK_APP_SECRET";
+pub const ENV_APP_TYPE: &str = "LARK_APP_TYPE";
+pub const ENV_DOMAIN: &str = "LARK_DOMAIN";
+pub const ENV_REQ_TIMEOUT: &str = "LARK_REQ_TIMEOUT";
+pub const ENV_ENABLE_TOKEN_CACHE: &str = "LARK_ENABLE_TOKEN_CACHE";
+pub const ENV_ENCRYPT_KEY: &str = "LARK_ENCRYPT_KEY";
+pub const ENV_VERIFICATION_TOKEN: &str = "LARK_VERIFICATION_TOKEN";
+
+/// 不带前缀的旧变量名, 仅在未设置带前缀的变量时读取
+///
+/// `DOMAIN` 容易与其他程序的设置冲突, 不再读取
+const LEGACY_ENV_KEYS: [(&str, &str); 7] = [
+    (ENV_APP_ID, "APP_ID"),
+    (ENV_APP_SECRET, "APP_SECRET"),
+    (ENV_APP_TYPE, "APP_TYPE"),
+    (ENV_REQ_TIMEOUT, "REQ_TIMEOUT"),
+    (ENV_ENABLE_TOKEN_CACHE, "ENABLE_TOKEN_CACHE"),
+    (ENV_ENCRYPT_KEY, "ENCRYPT_KEY"),
+    (ENV_VERIFICATION_TOKEN, "VERIFICATION_TOKEN"),
+];
+
+/// 单个应用的配置项, 未填写的字段使用默认值
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AppConfig {
+    pub app_id: Option<String>,
+    pub app_secret: Option<String>,
+    /// 应用类型, self_build 或 marketplace
+    pub app_type: Option<String>,
+    /// 域名, feishu、lark 或自定义地址
+    pub domain: Option<String>,
+    /// 请求超时时间, 单位秒
+    pub req_timeout: Option<f32>,
+    pub enable_token_cache: Option<bool>,
+    pub encrypt_key: Option<String>,
+    pub verification_token: Option<String>,
+}
+
+impl AppConfig {
+    /// 从环境变量键值对中读取配置, 变量名带 `LARK_` 前缀, 如 `LARK_APP_ID`
+    pub fn from_env_vars<I, K, V>(vars: I) -> SDKResult<Self>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: ToString,
+    {
+        let vars: HashMap<String, String> = vars
+            .into_iter()
+            .map(|(k, v)| (k.as_ref().to_string(), v.to_string()))
+            .collect();
+        let get = |key: &str| {
+            let legacy = LEGACY_ENV_KEYS
+                .iter()
+                .find(|(k, _)| *k == key)
+                .map(|(_, legacy)| *legacy);
+            [Some(key), legacy]
+                .into_iter()
+                .flatten()
+                .find_map(|key| vars.get(key).filter(|v| !v.is_empty()).cloned())
+        };
+
+        let req_timeout = match get(ENV_REQ_TIMEOUT) {
+            Some(v) => Some(
+                v.parse::<f32>()
+                    .map_err(|_| invalid_field("req_timeout", &v))?,
+            ),
+            None => None,
+        };
+        let enable_token_cache = match get(ENV_ENABLE_TOKEN_CACHE) {
+            Some(v) => Some(
+                v.parse::<bool>()
+                    .map_err(|_| invalid_field("enable_token_cache", &v))?,
+            ),
+            None => None,
+        };
+
+        Ok(Self {
+            app_id: get(ENV_APP_ID),
+            app_secret: get(ENV_APP_SECRET),
+            app_type: get(ENV_APP_TYPE),
+            domain: get(ENV_DOMAIN),
+            req_timeout,
+            enable_token_cache,
+            encrypt_key: get(ENV_ENCRYPT_KEY),
+            verification_token: get(ENV_VERIFICATION_TOKEN),
+        })
+    }
+
+    /// 用 `self` 中已填写的字段覆盖 `defaults`
+    pub fn merge(self, defaults: &AppConfig) -> AppConfig {
+        let defaults = defaults.clone();
+        AppConfig {
+            app_id: self.app_id.or(defaults.app_id),
+            app_secret: self.app_secret.or(defaults.app_secret),
+            app_type: self.app_type.or(defaults.app_type),
+            domain: self.domain.or(defaults.domain),
+            req_timeout: self.req_timeout.or(defaults.req_timeout),
+            enable_token_cache: self.enable_token_cache.or(defaults.enable_token_cache),
+            encrypt_key: self.encrypt_key.or(defaults.encrypt_key),
+            verification_token: self.verification_token.or(defaults.verification_token),
+        }
+    }
+
+    /// 校验并转换为客户端配置
+    pub fn into_config(self) -> SDKResult<Config> {
+        let app_id = required("app_id", self.app_id)?;
+        let app_secret = required("app_secret", self.app_secret)?;
+
+        let mut config = Config {
+            app_id,
+            app_secret: app_secret.into(),
+            encrypt_key: self.encrypt_key.map(Secret::from),
+            verification_token: self.verification_token.map(Secret::from),
+            ..Default::default()
+        };
+        if let Some(app_type) = self.app_type {
+            config.app_type = match app_type.to_lowercase().as_str() {
+                "self_build" | "selfbuild" => AppType::SelfBuild,
+                "marketplace" => AppType::Marketplace,
+                _ => return Err(invalid_field("app_type", &app_type)),
+            };
+        }
+        if let Some(domain) = self.domain {
+            config.domain = domain
+                .parse::<Domain>()
+                .map_err(|_| invalid_field("domain", &domain))?;
+        }
+        if let Some(req_timeout) = self.req_timeout {
+            let req_timeout = Duration::try_from_secs_f32(req_timeout)
+                .map_err(|_| invalid_field("req_timeout", &req_timeout.to_string()))?;
+            config.req_timeout = Some(req_timeout);
+        }
+        if let Some(enable_token_cache) = self.enable_token_cache {
+            config.enable_token_cache = enable_token_cache;
+        }
+
+        Ok(config)
+    }
+}
+
+/// 配置文件, 顶层为单应用配置或多应用的默认值
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ConfigFile {
+    #[serde(flatten)]
+    pub defaults: AppConfig,
+    #[serde(default)]
+    pub apps: HashMap<String, AppConfig>,
+}
+
+impl ConfigFile {
+    /// 根据扩展名解析配置文件, 支持 .toml、.json 和 .env
+    pub fn load(path: impl AsRef<Path>) -> SDKResult<Self> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default();
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        if extension == "env" || file_name.starts_with(".env") {
+            let vars = dotenvy::from_path_iter(path)
+                .map_err(|e| LarkAPIError::ConfigError(e.to_string()))?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| LarkAPIError::ConfigError(e.to_string()))?;
+            return Ok(Self {
+                defaults: AppConfig::from_env_vars(vars)?,
+                apps: HashMap::new(),
+            });
+        }
+
+        let content = fs::read_to_string(path)?;
+        match extension {
+            "toml" => Self::from_toml(&content),
+            "json" => Self::from_json(&content),
+            _ => Err(LarkAPIError::ConfigError(format!(
+                "unsupported config file format: {}",
+                path.display()
+            ))),
+        }
+    }
+
+    pub fn from_toml(content: &str) -> SDKResult<Self> {
+        toml::from_str(content).map_err(|e| LarkAPIError::ConfigError(e.to_string()))
+    }
+
+    pub fn from_json(content: &str) -> SDKResult<Self> {
+        Ok(serde_json::from_str(content)?)
+    }
+
+    /// 单应用配置
+    pub fn app(&self) -> SDKResult<Config> {
+        self.defaults.clone().into_config()
+    }
+
+    /// 多应用配置中的指定应用
+    pub fn named_app(&self, name: &str) -> SDKResult<Config> {
+        let app = self
+            .apps
+            .get(name)
+            .ok_or_else(|| LarkAPIError::MissingConfigField(format!("apps.{name}")))?;
+        app.clone()
+            .merge(&self.defaults)
+            .into_config()
+            .map_err(|e| match e {
+                LarkAPIError::MissingConfigField(field) => {
+                    LarkAPIError::MissingConfigField(format!("apps.{name}.{field}"))
+                }
+                e => e,
+            })
+    }
+
+    /// 多应用配置中的所有应用
+    pub fn apps(&self) -> SDKResult<HashMap<String, Config>> {
+        self.apps
+            .keys()
+            .map(|name| Ok((name.clone(), self.named_app(name)?)))
+            .collect()
+    }
+}
+
+fn required(field: &str, value: Option<String>) -> SDKResult<String> {
+    match value {
+        Some(value) if !value.is_empty() => Ok(value),
+        _ => Err(LarkAPIError::MissingConfigField(field.to_string())),
+    }
+}
+
+fn invalid_field(field: &str, value: &str) -> LarkAPIError {
+    LarkAPIError::ConfigError(format!("invalid value for {field}: {value}"))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::core::{
+        config_file::{AppConfig, ConfigFile},
+        constants::{AppType, Domain},
+        error::LarkAPIError,
+    };
+
+    #[test]
+    fn test_from_env_vars() {
+        let config = AppConfig::from_env_vars([
+            ("LARK_APP_ID", "cli_a1b2c3"),
+            ("LARK_APP_SECRET", "secret"),
+            ("LARK_APP_TYPE", "marketplace"),
+            ("LARK_DOMAIN", "lark"),
+            ("LARK_REQ_TIMEOUT", "2.5"),
+            ("LARK_ENCRYPT_KEY", "encrypt"),
+        ])
+        .unwrap()
+        .into_config()
+        .unwrap();
+
+        assert_eq!(config.app_id, "cli_a1b2c3");
+        assert_eq!(config.app_type, AppType::Marketplace);
+        assert_eq!(config.domain, Domain::Lark);
+        assert_eq!(config.req_timeout, Some(Duration::from_millis(2500)));
+        assert_eq!(
+            config.encrypt_key.as_ref().map(|k| k.expose()),
+            Some("encrypt")
+        );
+    }
+
+    #[test]
+    fn test_legacy_env_vars() {
+        let config = AppConfig::from_env_vars([
+            ("APP_ID", "cli_legacy"),
+            ("LARK_APP_ID", "cli_a1b2c3"),
+            ("APP_SECRET", "secret"),
+            ("DOMAIN", "lark"),
+        ])
+        .unwrap()
+        .into_config()
+        .unwrap();
+
+        assert_eq!(config.app_id, "cli_a1b2c3");
+        assert_eq!(config.app_secret.expose(), "secret");
+        // 不读取不带前缀的 DOMAIN
+        assert_eq!(config.domain, Domain::Feishu);
+    }
+
+    #[test]
+    fn test_invalid_domain() {
+        let config = |domain: &str| {
+            AppConfig::from_env_vars([
+                ("LARK_APP_ID", "cli_a1b2c3"),
+                ("LARK_APP_SECRET", "secret"),
+                ("LARK_DOMAIN", domain),
+            ])
+            .unwrap()
+            .into_config()
+        };
+
+        assert_eq!(
+            config("https://open.example.com/").unwrap().domain,
+            Domain::Custom("https://open.example.com/".to_string())
+        );
+        for domain in ["example.com", "ftp://open.example.com", "production"] {
+            let err = config(domain).unwrap_err();
+            assert!(matches!(err, LarkAPIError::ConfigError(msg) if msg.contains("domain")));
+        }
+    }
+
+    #[test]
+    fn test_missing_field() {
+        let err = AppConfig::from_env_vars([("LARK_APP_ID", "cli_a1b2c3")])
+            .unwrap()
+            .into_config()
+            .unwrap_err();
+        assert!(matches!(err, LarkAPIError::MissingConfigField(field) if field == "app_secret"));
+    }
+
+    #[test]
+    fn test_invalid_req_timeout() {
+        let err = AppConfig::from_env_vars([
+            ("LARK_APP_ID", "cli_a1b2c3"),
+            ("LARK_APP_SECRET", "secret"),
+            ("LARK_REQ_TIMEOUT", "-1"),
+        ])
+        .unwrap()
+        .into_config()
+        .unwrap_err();
+        assert!(matches!(err, LarkAPIError::ConfigError(msg) if msg.contains("req_timeout")));
+    }
+
+    #[test]
+    fn test_multi_app_toml() {
+        let file = ConfigFile::from_toml(
+            r#"
+            domain = "lark"
+
+            [apps.alert]
+            app_id = "cli_alert"
+            app_secret = "secret1"
+
+            [apps.ops]
+            app_id = "cli_ops"
+            domain = "feishu"
+            "#,
+        )
+        .unwrap();
+
+        let alert = file.named_app("alert").unwrap();
+        assert_eq!(alert.app_id, "cli_alert");
+        assert_eq!(alert.domain, Domain::Lark);
+
+        let err = file.named_app("ops").unwrap_err();
+        assert!(
+            matches!(err, LarkAPIError::MissingConfigField(field) if field == "apps.ops.app_secret")
+        );
+    }
+
+    #[test]
+    fn test_single_app_json() {
+        let file = ConfigFile::from_json(
+            r#"{"app_id": "cli_a1b2c3", "app_secret": "secret", "enable_token_cache": false}"#,
+        )
+        .unwrap();
+        let config = file.app().unwrap();
+        assert!(!config.enable_token_cache);
+        assert_eq!(config.domain, Domain::Feishu);
+    }
+}
@@ -1,4 +1,8 @@
-use std::{fmt::Display, time::Duration};
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use url::Url;
+
+use crate::core::error::LarkAPIError;
 
 /// 应用类型
 #[derive(Default, Hash, Eq, PartialEq, Debug, Copy, Clone)]
@@ -44,6 +48,74 @@ pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 /// Domain
 pub const FEISHU_BASE_URL: &str = "https://open.feishu.cn";
 pub const LARK_BASE_URL: &str = "https://open.larksuite.com";
+pub const FEISHU_APPLINK_BASE_URL: &str = "https://applink.feishu.cn";
+pub const LARK_APPLINK_BASE_URL: &str = "https://applink.larksuite.com";
+pub const FEISHU_ACCOUNTS_BASE_URL: &str = "https://accounts.feishu.cn";
+pub const LARK_ACCOUNTS_BASE_URL: &str = "https://accounts.larksuite.com";
+
+/// 开放平台域名
+#[derive(Default, Hash, Eq, PartialEq, Debug, Clone)]
+pub enum Domain {
+    /// 飞书, https://open.feishu.cn
+    #[default]
+    Feishu,
+    /// Lark 国际版, https://open.larksuite.com
+    Lark,
+    /// 自定义域名, 如私有化部署。所有请求及链接均使用该地址
+    Custom(String),
+}
+
+impl Domain {
+    /// 开放接口地址
+    pub fn open_base_url(&self) -> &str {
+        match self {
+            Domain::Feishu => FEISHU_BASE_URL,
+            Domain::Lark => LARK_BASE_URL,
+            Domain::Custom(url) => url.trim_end_matches('/'),
+        }
+    }
+
+    /// AppLink 协议地址
+    pub fn applink_base_url(&self) -> &str {
+        match self {
+            Domain::Feishu => FEISHU_APPLINK_BASE_URL,
+            Domain::Lark => LARK_APPLINK_BASE_URL,
+            Domain::Custom(url) => url.trim_end_matches('/'),
+        }
+    }
+
+    /// 账号授权地址
+    pub fn accounts_base_url(&self) -> &str {
+        match self {
+            Domain::Feishu => FEISHU_ACCOUNTS_BASE_URL,
+            Domain::Lark => LARK_ACCOUNTS_BASE_URL,
+            Domain::Custom(url) => url.trim_end_matches('/'),
+        }
+    }
+}
+
+impl FromStr for Domain {
+    type Err = LarkAPIError;
+
+    /// 支持 `feishu`、`lark` 以及 http:// 或 https:// 开头的自定义地址
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let domain = match s.trim().to_lowercase().as_str() {
+            "feishu" | FEISHU_BASE_URL => Domain::Feishu,
+            "lark" | "larksuite" | LARK_BASE_URL => Domain::Lark,
+            _ => match Url::parse(s.trim()) {
+                Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {
+                    Domain::Custom(s.trim().to_string())
+                }
+                _ => {
+                    return Err(LarkAPIError::IllegalParamError(format!(
+                        "invalid domain: {s}, expected feishu, lark or an http(s):// url"
+                    )))
+                }
+            },
+        };
+        Ok(domain)
+    }
+}
 
 pub const DEFAULT_CONTENT_TYPE: &str = "application/json; charset=utf-8";
 pub const FILE_CONTENT_TYPE: &str = "multipart/form-data";
@@ -1,5 +1,7 @@
 use thiserror::Error;
 
+use crate::custom_bot::error::CustomBotError;
+
 #[derive(Error, Debug)]
 pub enum LarkAPIError {
     #[error("IO error: {0}")]
@@ -12,4 +14,12 @@ pub enum LarkAPIError {
     RequestError(#[from] reqwest::Error),
     #[error("Url parse error: {0}")]
     UrlParseError(#[from] url::ParseError),
+    #[error("Missing config field: {0}")]
+    MissingConfigField(String),
+    #[error("Config error: {0}")]
+    ConfigError(String),
+    #[error("Event error: {0}")]
+    EventError(String),
+    #[error("Custom bot error: {0}")]
+    CustomBotError(#[from] CustomBotError),
 }
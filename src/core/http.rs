@@ -13,6 +13,7 @@ use crate::core::{
     error::LarkAPIError,
     req_option::RequestOption,
     req_translator::ReqTranslator,
+    secret::redact_json,
     SDKResult,
 };
 
@@ -77,13 +78,13 @@ impl<T: ApiResponseTrait> Transport<T> {
                 match T::data_format() {
                     ResponseFormat::Data => {
                         let raw_body: Value = response.json().await?;
-                        debug!("raw_body: {:?}", raw_body);
+                        debug!("raw_body: {:?}", redact_json(&raw_body));
                         let base_resp = serde_json::from_value::<BaseResponse<T>>(raw_body)?;
                         Ok(base_resp)
                     }
                     ResponseFormat::Flatten => {
                         let raw_body: Value = response.json().await?;
-                        debug!("raw_body: {:?}", raw_body);
+                        debug!("raw_body: {:?}", redact_json(&raw_body));
                         let raw_response = serde_json::from_value::<RawResponse>(raw_body.clone())?;
 
                         let data = if raw_response.code == 0 {
@@ -3,13 +3,19 @@ use crate::core::error::LarkAPIError;
 pub mod api_req;
 pub mod api_resp;
 pub mod app_ticket_manager;
+pub mod applink;
 pub mod cache;
 pub mod config;
+pub mod config_file;
 pub mod constants;
 pub mod error;
 pub mod http;
+pub mod oauth;
 pub mod req_option;
 pub mod req_translator;
+pub mod secret;
+#[cfg(any(test, feature = "test-util"))]
+pub(crate) mod test_http;
 pub mod token_manager;
 pub mod utils;
 // pub mod multi_part;
@@ -0,0 +1,105 @@
+use url::Url;
+
+use crate::core::{config::Config, constants::Domain};
+
+pub const AUTHORIZE_URL_PATH: &str = "/open-apis/authen/v1/authorize";
+
+/// 网页应用登录授权链接
+///
+/// [获取授权码](https://open.feishu.cn/document/common-capabilities/sso/api/obtain-oauth-code)
+#[derive(Debug, Clone, Default)]
+pub struct AuthorizeUrl {
+    domain: Domain,
+    client_id: String,
+    redirect_uri: String,
+    scope: Option<String>,
+    state: Option<String>,
+}
+
+impl AuthorizeUrl {
+    pub fn builder() -> AuthorizeUrlBuilder {
+        AuthorizeUrlBuilder::default()
+    }
+
+    /// 使用客户端配置中的 app_id 与域名
+    pub fn from_config(config: &Config) -> AuthorizeUrlBuilder {
+        AuthorizeUrlBuilder::default()
+            .domain(config.domain.clone())
+            .client_id(&config.app_id)
+    }
+}
+
+#[derive(Default)]
+pub struct AuthorizeUrlBuilder {
+    url: AuthorizeUrl,
+}
+
+impl AuthorizeUrlBuilder {
+    pub fn domain(mut self, domain: Domain) -> Self {
+        self.url.domain = domain;
+        self
+    }
+
+    /// 应用的 App ID
+    pub fn client_id(mut self, client_id: impl ToString) -> Self {
+        self.url.client_id = client_id.to_string();
+        self
+    }
+
+    /// 应用重定向地址，需在开发者后台的安全设置中配置
+    pub fn redirect_uri(mut self, redirect_uri: impl ToString) -> Self {
+        self.url.redirect_uri = redirect_uri.to_string();
+        self
+    }
+
+    /// 用户需要增量授予应用的权限，多个权限以空格分隔
+    pub fn scope(mut self, scope: impl ToString) -> Self {
+        self.url.scope = Some(scope.to_string());
+        self
+    }
+
+    /// 用来维护请求和回调状态的附加字符串，在授权完成回调时会原样回传
+    pub fn state(mut self, state: impl ToString) -> Self {
+        self.url.state = Some(state.to_string());
+        self
+    }
+
+    pub fn build(self) -> String {
+        let url = self.url;
+        let mut params = vec![
+            ("client_id", url.client_id),
+            ("redirect_uri", url.redirect_uri),
+        ];
+        if let Some(scope) = url.scope {
+            params.push(("scope", scope));
+        }
+        if let Some(state) = url.state {
+            params.push(("state", state));
+        }
+
+        let base = format!("{}{AUTHORIZE_URL_PATH}", url.domain.accounts_base_url());
+        match Url::parse_with_params(&base, &params) {
+            Ok(url) => url.to_string(),
+            Err(_) => base,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::{constants::Domain, oauth::AuthorizeUrl};
+
+    #[test]
+    fn test_authorize_url() {
+        let url = AuthorizeUrl::builder()
+            .domain(Domain::Lark)
+            .client_id("cli_a5d611352af9d00b")
+            .redirect_uri("https://example.com/api/oauth/callback")
+            .state("RANDOMSTATE")
+            .build();
+        assert_eq!(
+            url,
+            "https://accounts.larksuite.com/open-apis/authen/v1/authorize?client_id=cli_a5d611352af9d00b&redirect_uri=https%3A%2F%2Fexample.com%2Fapi%2Foauth%2Fcallback&state=RANDOMSTATE"
+        );
+    }
+}
@@ -2,15 +2,17 @@
 
 use std::collections::HashMap;
 
+use crate::core::secret::Secret;
+
 #[derive(Debug, Clone, Default)]
 pub struct RequestOption {
     pub(crate) tenant_key: String,
-    pub(crate) user_access_token: String,
-    pub(crate) app_access_token: String,
-    pub(crate) tenant_access_token: String,
+    pub(crate) user_access_token: Secret,
+    pub(crate) app_access_token: Secret,
+    pub(crate) tenant_access_token: Secret,
     pub(crate) need_helpdesk_auth: bool,
     pub(crate) request_id: String,
-    pub(crate) app_ticket: String,
+    pub(crate) app_ticket: Secret,
     pub(crate) file_upload: bool,
     pub(crate) file_download: bool,
     pub(crate) header: HashMap<String, String>,
@@ -34,17 +36,17 @@ impl RequestOptionBuilder {
     }
 
     pub fn user_access_token(mut self, user_access_token: impl ToString) -> Self {
-        self.option.user_access_token = user_access_token.to_string();
+        self.option.user_access_token = user_access_token.to_string().into();
         self
     }
 
     pub fn app_access_token(mut self, app_access_token: impl ToString) -> Self {
-        self.option.app_access_token = app_access_token.to_string();
+        self.option.app_access_token = app_access_token.to_string().into();
         self
     }
 
     pub fn tenant_access_token(mut self, tenant_access_token: impl ToString) -> Self {
-        self.option.tenant_access_token = tenant_access_token.to_string();
+        self.option.tenant_access_token = tenant_access_token.to_string().into();
         self
     }
 
@@ -59,7 +61,7 @@ impl RequestOptionBuilder {
     }
 
     pub fn app_ticket(mut self, app_ticket: impl ToString) -> Self {
-        self.option.app_ticket = app_ticket.to_string();
+        self.option.app_ticket = app_ticket.to_string().into();
         self
     }
 
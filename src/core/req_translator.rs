@@ -1,8 +1,8 @@
-use std::collections::HashMap;
-
 use async_recursion::async_recursion;
-use reqwest::{multipart, RequestBuilder};
-use serde::{Deserialize, Serialize};
+use reqwest::{
+    header::{HeaderValue, AUTHORIZATION},
+    multipart, RequestBuilder,
+};
 use serde_json::Value;
 use url::Url;
 
@@ -17,7 +17,6 @@ use crate::core::{
     // multi_part::MultipartBuilder,
     req_option::RequestOption,
     token_manager::TOKEN_MANAGER,
-    utils::user_agent,
 };
 
 pub struct ReqTranslator;
@@ -30,9 +29,7 @@ impl ReqTranslator {
         config: &Config,
         option: &RequestOption,
     ) -> Result<RequestBuilder, LarkAPIError> {
-        let client = reqwest::Client::new();
-
-        let path = format!("{}{}", config.base_url, req.api_path);
+        let path = format!("{}{}", config.domain.open_base_url(), req.api_path);
         let query_params = req
             .query_params
             .iter()
@@ -40,7 +37,9 @@ impl ReqTranslator {
             .collect::<Vec<_>>();
         let url = Url::parse_with_params(&path, query_params)?;
 
-        let mut req_builder = client.request(req.http_method.clone(), url.as_ref());
+        let mut req_builder = config
+            .http_client
+            .request(req.http_method.clone(), url.as_ref());
         // .send_bytes(&req.body);
         if !option.request_id.is_empty() {
             req_builder = req_builder.header(CUSTOM_REQUEST_ID, &option.request_id.clone());
@@ -52,29 +51,33 @@ impl ReqTranslator {
         for (k, v) in &config.header {
             req_builder = req_builder.header(k, v)
         }
-        req_builder = req_builder.header(USER_AGENT_HEADER, &user_agent());
+        req_builder = req_builder.header(USER_AGENT_HEADER, config.user_agent());
 
         match access_token_type {
             AccessTokenType::None => {}
             AccessTokenType::App => {
-                let mut app_access_token = option.app_access_token.clone();
+                let mut app_access_token = option.app_access_token.expose().to_string();
                 if config.enable_token_cache && app_access_token.is_empty() {
                     {
                         let mut token_manager = TOKEN_MANAGER.lock().await;
                         app_access_token = token_manager
-                            .get_app_access_token(config, &option.app_ticket)
+                            .get_app_access_token(config, option.app_ticket.expose())
                             .await?
                     }
                 }
                 req_builder = authorization_to_header(req_builder, &app_access_token);
             }
             AccessTokenType::Tenant => {
-                let mut tenant_access_token = option.tenant_access_token.clone();
+                let mut tenant_access_token = option.tenant_access_token.expose().to_string();
                 if config.enable_token_cache {
                     {
                         let mut token_manager = TOKEN_MANAGER.lock().await;
                         tenant_access_token = token_manager
-                            .get_tenant_access_token(config, &option.tenant_key, &option.app_ticket)
+                            .get_tenant_access_token(
+                                config,
+                                &option.tenant_key,
+                                option.app_ticket.expose(),
+                            )
                             .await?;
                     }
                 }
@@ -82,7 +85,8 @@ impl ReqTranslator {
                 req_builder = authorization_to_header(req_builder, &tenant_access_token);
             }
             AccessTokenType::User => {
-                req_builder = authorization_to_header(req_builder, &option.user_access_token);
+                req_builder =
+                    authorization_to_header(req_builder, option.user_access_token.expose());
             }
         }
 
@@ -130,22 +134,15 @@ impl ReqTranslator {
     }
 }
 
+/// 访问凭证标记为敏感头, 打印请求时不会输出
 fn authorization_to_header(req: RequestBuilder, token: &str) -> RequestBuilder {
-    req.header("Authorization", &format!("Bearer {token}"))
-}
-
-#[derive(Debug, Default, Serialize, Deserialize)]
-struct FormData {
-    fields: HashMap<String, Value>,
-    data: Option<Data>,
-}
-
-impl FormData {}
-
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
-struct Data {
-    content: Vec<u8>,
-    content_type: String,
+    match HeaderValue::from_str(&format!("Bearer {token}")) {
+        Ok(mut value) => {
+            value.set_sensitive(true);
+            req.header(AUTHORIZATION, value)
+        }
+        Err(_) => req,
+    }
 }
 
 // async fn to_form_data(body: Bytes) -> Result<Form, LarkAPIError> {
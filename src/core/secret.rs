@@ -0,0 +1,136 @@
+use std::fmt::{Debug, Formatter};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+use zeroize::Zeroize;
+
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// 日志中需要脱敏的字段
+const SENSITIVE_KEYS: [&str; 10] = [
+    "access_token",
+    "refresh_token",
+    "app_access_token",
+    "tenant_access_token",
+    "user_access_token",
+    "app_secret",
+    "app_ticket",
+    "encrypt_key",
+    "token",
+    "ticket",
+];
+
+/// 敏感信息, 如密钥、访问凭证等
+///
+/// `Debug` 输出会被脱敏, 释放时清零内存。需要原文时调用 [`Secret::expose`]。
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// 获取原文
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            write!(f, "\"\"")
+        } else {
+            write!(f, "{REDACTED}")
+        }
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Self(value.to_string())
+    }
+}
+
+impl Serialize for Secret {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Secret {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self(String::deserialize(deserializer)?))
+    }
+}
+
+/// 返回脱敏后的 JSON, 用于日志输出
+pub fn redact_json(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| {
+                    let v = if SENSITIVE_KEYS.contains(&k.as_str()) && v.is_string() {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        redact_json(v)
+                    };
+                    (k.clone(), v)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_json).collect()),
+        v => v.clone(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::core::secret::{redact_json, Secret};
+
+    #[test]
+    fn test_secret_debug() {
+        let secret = Secret::new("app_secret_value");
+        assert_eq!(format!("{:?}", secret), "[REDACTED]");
+        assert_eq!(secret.expose(), "app_secret_value");
+        assert_eq!(json!(secret), json!("app_secret_value"));
+    }
+
+    #[test]
+    fn test_redact_json() {
+        let value = json!({
+            "code": 0,
+            "expire": 7200,
+            "tenant_access_token": "t-caecc734c2e3328a62489fe0648c4b98779515d3",
+            "data": {"items": [{"app_secret": "secret", "name": "name"}]}
+        });
+        assert_eq!(
+            redact_json(&value),
+            json!({
+                "code": 0,
+                "expire": 7200,
+                "tenant_access_token": "[REDACTED]",
+                "data": {"items": [{"app_secret": "[REDACTED]", "name": "name"}]}
+            })
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! 测试用的最小 HTTP/1.1 服务端工具, 每个连接只处理一个请求
+use serde_json::Value;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+};
+
+/// 收到的请求, 路径不含查询参数
+#[derive(Debug)]
+pub(crate) struct HttpRequest {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+/// 读取一个完整的请求, 按 Content-Length 读取请求体, 连接提前关闭时返回 None
+pub(crate) async fn read_request(stream: &mut TcpStream) -> Option<HttpRequest> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    loop {
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            let head = String::from_utf8_lossy(&buf[..pos]).to_string();
+            let length: usize = head
+                .lines()
+                .find_map(|line| {
+                    let (key, value) = line.split_once(':')?;
+                    key.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse().ok())?
+                })
+                .unwrap_or(0);
+            if buf.len() >= pos + 4 + length {
+                let mut request_line = head.lines().next()?.split(' ');
+                let method = request_line.next()?.to_string();
+                let path = request_line.next()?.split('?').next()?.to_string();
+                return Some(HttpRequest {
+                    method,
+                    path,
+                    body: buf[pos + 4..pos + 4 + length].to_vec(),
+                });
+            }
+        }
+        match stream.read(&mut chunk).await {
+            Ok(0) | Err(_) => return None,
+            Ok(n) => buf.extend_from_slice(&chunk[..n]),
+        }
+    }
+}
+
+/// 返回 JSON 响应并关闭连接
+pub(crate) async fn write_json(
+    stream: &mut TcpStream,
+    status: &str,
+    body: &Value,
+) -> std::io::Result<()> {
+    let body = body.to_string();
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
@@ -18,6 +18,7 @@ use crate::core::{
     },
     error::LarkAPIError,
     http::Transport,
+    secret::Secret,
     SDKResult,
 };
 
@@ -88,11 +89,11 @@ impl TokenManager {
             let expire = Duration::from_secs(data.expire as u64) - EXPIRY_DELTA;
             self.set(
                 &app_access_token_key(&config.app_id),
-                &data.app_access_token,
+                data.app_access_token.expose(),
                 expire,
             );
 
-            Ok(data.app_access_token)
+            Ok(data.app_access_token.expose().to_string())
         } else {
             warn!("custom app appAccessToken cache {:#?}", resp.raw_response);
             Err(LarkAPIError::IllegalParamError(resp.msg().to_string()))
@@ -120,7 +121,7 @@ impl TokenManager {
         let body = serde_json::to_vec(&MarketplaceAppAccessTokenReq {
             app_id: config.app_id.clone(),
             app_secret: config.app_secret.clone(),
-            app_ticket,
+            app_ticket: app_ticket.into(),
         })?;
 
         let req = ApiRequest {
@@ -138,11 +139,11 @@ impl TokenManager {
 
             self.set(
                 &app_access_token_key(&config.app_id),
-                &data.app_access_token,
+                data.app_access_token.expose(),
                 expire,
             );
 
-            Ok(data.app_access_token)
+            Ok(data.app_access_token.expose().to_string())
         } else {
             warn!(
                 "marketplace app appAccessToken cache {:#?}",
@@ -202,11 +203,11 @@ impl TokenManager {
 
             self.set(
                 &tenant_access_token_key(&config.app_id, tenant_key),
-                &data.tenant_access_token,
+                data.tenant_access_token.expose(),
                 expire,
             );
 
-            Ok(data.tenant_access_token)
+            Ok(data.tenant_access_token.expose().to_string())
         } else {
             warn!(
                 "custom app tenantAccessToken cache {:#?}",
@@ -227,7 +228,7 @@ impl TokenManager {
             .await?;
 
         let body = serde_json::to_vec(&MarketplaceTenantAccessTokenReq {
-            app_access_token,
+            app_access_token: app_access_token.into(),
             tenant_key: tenant_key.to_string(),
         })?;
 
@@ -247,11 +248,11 @@ impl TokenManager {
 
             self.set(
                 &tenant_access_token_key(&config.app_id, tenant_key),
-                &data.tenant_access_token,
+                data.tenant_access_token.expose(),
                 expire,
             );
 
-            Ok(data.tenant_access_token)
+            Ok(data.tenant_access_token.expose().to_string())
         } else {
             warn!(
                 "marketplace app tenantAccessToken cache {:#?}",
@@ -273,13 +274,7 @@ fn tenant_access_token_key(app_id: &str, tenant_key: &str) -> String {
 #[derive(Debug, Serialize, Deserialize)]
 struct SelfBuiltAppAccessTokenReq {
     app_id: String,
-    app_secret: String,
-}
-
-#[derive(Debug, Serialize, Deserialize)]
-struct SelfBuiltTenantAccessTokenReq {
-    app_id: String,
-    app_secret: String,
+    app_secret: Secret,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -287,7 +282,7 @@ struct AppAccessTokenResp {
     #[serde(flatten)]
     raw_response: RawResponse,
     expire: i32,
-    app_access_token: String,
+    app_access_token: Secret,
 }
 
 impl ApiResponseTrait for AppAccessTokenResp {
@@ -299,13 +294,13 @@ impl ApiResponseTrait for AppAccessTokenResp {
 #[derive(Serialize, Deserialize)]
 struct MarketplaceAppAccessTokenReq {
     app_id: String,
-    app_secret: String,
-    app_ticket: String,
+    app_secret: Secret,
+    app_ticket: Secret,
 }
 
 #[derive(Serialize, Deserialize)]
 struct MarketplaceTenantAccessTokenReq {
-    app_access_token: String,
+    app_access_token: Secret,
     tenant_key: String,
 }
 
@@ -314,7 +309,7 @@ struct TenantAccessTokenResp {
     #[serde(flatten)]
     raw_response: RawResponse,
     expire: i32,
-    tenant_access_token: String,
+    tenant_access_token: Secret,
 }
 
 impl ApiResponseTrait for TenantAccessTokenResp {
@@ -1,73 +1,168 @@
-use base64::{Engine, prelude::BASE64_STANDARD};
+use std::{
+    fmt::{Debug, Formatter},
+    sync::Arc,
+};
+
+use base64::{prelude::BASE64_STANDARD, Engine};
 use hmac::{Hmac, Mac};
-use serde_json::{json, Value};
+use serde_json::{Map, Value};
 use sha2::Sha256;
 
 use crate::{
     core::{
-        api_resp::{RawResponse},
+        api_resp::{BaseResponse, RawResponse},
+        config::Config,
         http::Transport,
+        secret::{Secret, REDACTED},
         SDKResult,
     },
+    custom_bot::{error::CustomBotError, rate_limit::RateLimiter},
     service::im::v1::message::{MessageCardTemplate, SendMessageTrait},
 };
-use crate::core::api_resp::BaseResponse;
+
+pub mod error;
+mod group;
+mod rate_limit;
+
+pub use group::{CustomBotGroup, CustomBotTarget, TargetResult};
+pub use rate_limit::RateLimit;
 
 /// 自定义机器人
 ///
+/// webhook 返回的错误码会转换为 [`CustomBotError`]
+///
 /// [使用指南](https://open.feishu.cn/document/client-docs/bot-v3/add-custom-bot)
+#[derive(Clone)]
 pub struct CustomBot {
-    /// webhook 地址
+    /// webhook 地址, 最后一段为机器人的 token
     webhook_url: String,
     /// 密钥
-    secret: Option<String>,
+    secret: Option<Secret>,
     client: reqwest::Client,
+    /// 发送队列, 克隆出的机器人共享同一个队列
+    rate_limiter: Option<Arc<RateLimiter>>,
+}
+
+impl Debug for CustomBot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // webhook 地址中的 token 可以直接发送消息, 与密钥一样需要脱敏
+        let webhook_url = match self.webhook_url.rsplit_once('/') {
+            Some((prefix, _)) => format!("{prefix}/{REDACTED}"),
+            None => REDACTED.to_string(),
+        };
+        f.debug_struct("CustomBot")
+            .field("webhook_url", &webhook_url)
+            .field("secret", &self.secret)
+            .field("client", &self.client)
+            .field("rate_limiter", &self.rate_limiter)
+            .finish()
+    }
 }
 
 impl CustomBot {
     pub fn new(webhook_url: String, secret: Option<String>) -> Self {
         CustomBot {
             webhook_url,
-            secret,
+            secret: secret.map(Secret::from),
             client: reqwest::Client::new(),
+            rate_limiter: None,
         }
     }
+
+    /// 复用客户端配置中的 HTTP 客户端, 包括代理、根证书和 User-Agent 设置
+    pub fn with_config(mut self, config: &Config) -> Self {
+        self.client = config.http_client.clone();
+        self
+    }
+
+    /// 使用自定义的 HTTP 客户端
+    pub fn with_http_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// 开启发送队列, 按频率限制排队发送, 频率超限时自动重试
+    pub fn with_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(limit)));
+        self
+    }
 }
 
 impl CustomBot {
+    /// 发送消息, 支持文本、富文本、图片、分享群名片、飞书卡片和卡片模板
     pub async fn send_message(
         &self,
         message: impl SendMessageTrait,
     ) -> SDKResult<BaseResponse<RawResponse>> {
-        let mut json = json!({
-            "msg_type": message.msg_type(),
-            "content": message.content()
-        });
+        self.send(Self::webhook_body(&message)?).await
+    }
 
-        self.check_sign(&mut json);
-        // let json_string = json.to_string().into_bytes();
+    /// 发送卡片模板消息
+    pub async fn send_card(
+        &self,
+        message: MessageCardTemplate,
+    ) -> SDKResult<BaseResponse<RawResponse>> {
+        self.send_message(message).await
+    }
+
+    /// webhook 的请求体
+    ///
+    /// 消息内容以对象而非字符串的形式发送, 卡片放在 `card` 字段中,
+    /// 分享群名片的群 ID 字段为 `share_chat_id`
+    fn webhook_body(message: &impl SendMessageTrait) -> SDKResult<Value> {
+        let msg_type = message.msg_type();
+        let mut content: Value = serde_json::from_str(&message.content())?;
+        let key = match msg_type.as_str() {
+            "interactive" => "card",
+            "share_chat" => {
+                if let Some(chat_id) = content.get("chat_id").cloned() {
+                    content =
+                        Value::Object(Map::from_iter([("share_chat_id".to_string(), chat_id)]));
+                }
+                "content"
+            }
+            _ => "content",
+        };
 
-        Transport::do_send(
-            self.client.post(&self.webhook_url),
-            json.to_string().into_bytes(),
-            false,
-        ).await
+        let mut body = Map::new();
+        body.insert("msg_type".to_string(), Value::String(msg_type));
+        body.insert(key.to_string(), content);
+        Ok(Value::Object(body))
     }
 
-    /// 发送飞书卡片消息， 因为自定义机器人发送飞书卡片消息的格式比较特殊，所以单独提供一个方法
-    pub async fn send_card(&self, message: MessageCardTemplate) -> SDKResult<BaseResponse<RawResponse>> {
-        let mut json = json!({
-            "msg_type": message.msg_type(),
-            "card": message.content()
-        });
+    /// 发送请求, 开启发送队列时排队并在频率超限时重试
+    async fn send(&self, body: Value) -> SDKResult<BaseResponse<RawResponse>> {
+        let mut retries = 0;
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await;
+            }
 
-        self.check_sign(&mut json);
+            // 每次发送重新计算签名, 避免重试时时间戳过期
+            let mut json = body.clone();
+            self.check_sign(&mut json);
+            let resp: BaseResponse<RawResponse> = Transport::do_send(
+                self.client.post(&self.webhook_url),
+                json.to_string().into_bytes(),
+                false,
+            )
+            .await?;
 
-        Transport::do_send(
-            self.client.post(&self.webhook_url),
-            json.to_string().into_bytes(),
-            false,
-        ).await
+            let err = match CustomBotError::from_code(resp.code(), resp.msg()) {
+                None => return Ok(resp),
+                Some(err) => err,
+            };
+            match &self.rate_limiter {
+                Some(rate_limiter)
+                    if matches!(err, CustomBotError::RateLimited(_))
+                        && retries < rate_limiter.limit().max_retries =>
+                {
+                    retries += 1;
+                    tokio::time::sleep(rate_limiter.limit().retry_backoff * retries).await;
+                }
+                _ => return Err(err.into()),
+            }
+        }
     }
 
     /// 如果设置了密钥，就计算签名
@@ -75,7 +170,7 @@ impl CustomBot {
         if let Some(secret) = self.secret.as_ref() {
             let now = chrono::Local::now().timestamp();
             json["timestamp"] = serde_json::to_value(now).unwrap();
-            let sign = CustomBot::sign(now, secret);
+            let sign = CustomBot::sign(now, secret.expose());
             json["sign"] = serde_json::to_value(sign).unwrap();
         }
     }
@@ -88,3 +183,193 @@ impl CustomBot {
         BASE64_STANDARD.encode(hmac_code)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration,
+    };
+
+    use serde_json::{json, Value};
+    use tokio::net::TcpListener;
+
+    use crate::{
+        card::FeishuCard,
+        core::{
+            error::LarkAPIError,
+            test_http::{read_request, write_json},
+        },
+        custom_bot::{error::CustomBotError, CustomBot, RateLimit},
+        service::im::v1::message::{
+            MessageCardTemplate, MessageImage, MessagePost, MessagePostNode, MessageShareChat,
+            MessageText, TextNode,
+        },
+    };
+
+    /// 依次返回给定响应的 webhook, 记录收到的请求体
+    pub(crate) async fn webhook(responses: Vec<Value>) -> (String, Arc<Mutex<Vec<Value>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!(
+            "http://{}/open-apis/bot/v2/hook/xxx",
+            listener.local_addr().unwrap()
+        );
+        let bodies = Arc::new(Mutex::new(vec![]));
+        let received = Arc::clone(&bodies);
+
+        tokio::spawn(async move {
+            for response in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let request = read_request(&mut stream).await.unwrap();
+                received
+                    .lock()
+                    .unwrap()
+                    .push(serde_json::from_slice(&request.body).unwrap());
+
+                write_json(&mut stream, "200 OK", &response).await.unwrap();
+            }
+        });
+
+        (url, bodies)
+    }
+
+    #[test]
+    fn test_debug_redacts_token() {
+        let bot = CustomBot::new(
+            "https://open.feishu.cn/open-apis/bot/v2/hook/4f2bba05-bbc5-4ec5-9a3d-b1c4d5e6f7a8"
+                .to_string(),
+            Some("bot_secret".to_string()),
+        );
+        let debug = format!("{bot:?}");
+        assert!(debug.contains("https://open.feishu.cn/open-apis/bot/v2/hook/[REDACTED]"));
+        assert!(!debug.contains("4f2bba05"));
+        assert!(!debug.contains("bot_secret"));
+    }
+
+    #[tokio::test]
+    async fn test_error_code() {
+        let (url, bodies) = webhook(vec![json!({
+            "code": 19021,
+            "msg": "sign match fail or timestamp is not within one hour from current time",
+            "data": {}
+        })])
+        .await;
+        let bot = CustomBot::new(url, Some("secret".to_string()));
+
+        let err = bot
+            .send_message(MessageText::new("hello"))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            LarkAPIError::CustomBotError(CustomBotError::SignatureMismatch(_))
+        ));
+        let body = &bodies.lock().unwrap()[0];
+        assert!(body["timestamp"].is_i64());
+        assert!(body["sign"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_retry_rate_limited() {
+        let (url, bodies) = webhook(vec![
+            json!({"code": 9499, "msg": "too many request", "data": {}}),
+            json!({"code": 0, "msg": "success", "data": {}}),
+        ])
+        .await;
+        let bot = CustomBot::new(url, None).with_rate_limit(RateLimit {
+            retry_backoff: Duration::from_millis(10),
+            ..Default::default()
+        });
+
+        let resp = bot.send_message(MessageText::new("hello")).await.unwrap();
+        assert!(resp.success());
+        assert_eq!(bodies.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_body() {
+        let success = json!({"code": 0, "msg": "success", "data": {}});
+        let (url, bodies) = webhook(vec![success; 6]).await;
+        let bot = CustomBot::new(url, None);
+
+        bot.send_message(MessageText::new("新更新提醒"))
+            .await
+            .unwrap();
+        bot.send_message(
+            MessagePost::new("zh_cn")
+                .title("项目更新通知")
+                .append_content(vec![MessagePostNode::Text(TextNode::new("项目有更新"))]),
+        )
+        .await
+        .unwrap();
+        bot.send_message(MessageImage::new(
+            "img_ecffc3b9-8f14-400f-a014-05eca1a4310g",
+        ))
+        .await
+        .unwrap();
+        bot.send_message(MessageShareChat::new("oc_f5b1a7eb27ae2c7b6adc2a74faf339ff"))
+            .await
+            .unwrap();
+        bot.send_message(FeishuCard::default()).await.unwrap();
+        bot.send_card(MessageCardTemplate::new(
+            "AAqk4PdEIBaSV",
+            json!({"project_name": "open-lark"}),
+        ))
+        .await
+        .unwrap();
+
+        let bodies = bodies.lock().unwrap();
+        assert_eq!(
+            bodies[0],
+            json!({"msg_type": "text", "content": {"text": "新更新提醒"}})
+        );
+        assert_eq!(
+            bodies[1],
+            json!({
+                "msg_type": "post",
+                "content": {
+                    "post": {
+                        "zh_cn": {
+                            "title": "项目更新通知",
+                            "content": [[{"tag": "text", "text": "项目有更新"}]]
+                        }
+                    }
+                }
+            })
+        );
+        assert_eq!(
+            bodies[2],
+            json!({
+                "msg_type": "image",
+                "content": {"image_key": "img_ecffc3b9-8f14-400f-a014-05eca1a4310g"}
+            })
+        );
+        assert_eq!(
+            bodies[3],
+            json!({
+                "msg_type": "share_chat",
+                "content": {"share_chat_id": "oc_f5b1a7eb27ae2c7b6adc2a74faf339ff"}
+            })
+        );
+        assert_eq!(
+            bodies[4],
+            json!({
+                "msg_type": "interactive",
+                "card": {"i18n_header": {}, "i18n_elements": {}}
+            })
+        );
+        assert_eq!(
+            bodies[5],
+            json!({
+                "msg_type": "interactive",
+                "card": {
+                    "type": "template",
+                    "data": {
+                        "template_id": "AAqk4PdEIBaSV",
+                        "template_variable": {"project_name": "open-lark"}
+                    }
+                }
+            })
+        );
+    }
+}
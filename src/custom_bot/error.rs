@@ -0,0 +1,105 @@
+//! 自定义机器人 webhook 的错误码
+//!
+//! [常见问题](https://open.feishu.cn/document/client-docs/bot-v3/add-custom-bot#4996824a)
+use thiserror::Error;
+
+/// webhook 地址无效
+pub const CODE_INVALID_WEBHOOK: i32 = 19001;
+/// 请求参数错误
+pub const CODE_BAD_REQUEST: i32 = 19002;
+/// 机器人已被停用
+pub const CODE_BOT_DISABLED: i32 = 19007;
+/// 签名校验失败, 或时间戳与当前时间相差超过 1 小时
+pub const CODE_SIGNATURE_MISMATCH: i32 = 19021;
+/// 请求来源 IP 不在白名单中
+pub const CODE_IP_NOT_ALLOWED: i32 = 19022;
+/// 消息中不包含设置的关键词
+pub const CODE_KEYWORD_MISMATCH: i32 = 19024;
+/// 请求频率超限
+pub const CODE_RATE_LIMITED: i32 = 9499;
+/// 请求频率超限, 部分场景返回该错误码
+pub const CODE_FREQUENCY_LIMITED: i32 = 11232;
+
+/// 自定义机器人 webhook 返回的错误
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum CustomBotError {
+    #[error("Invalid webhook: {0}")]
+    InvalidWebhook(String),
+    #[error("Bad request: {0}")]
+    BadRequest(String),
+    #[error("Bot disabled: {0}")]
+    BotDisabled(String),
+    #[error("Signature mismatch: {0}")]
+    SignatureMismatch(String),
+    #[error("IP not allowed: {0}")]
+    IpNotAllowed(String),
+    #[error("Keyword mismatch: {0}")]
+    KeywordMismatch(String),
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+    #[error("Webhook error: {code}, {msg}")]
+    Other { code: i32, msg: String },
+}
+
+impl CustomBotError {
+    /// 根据 webhook 返回的错误码构造错误, 成功时返回 None
+    pub fn from_code(code: i32, msg: impl ToString) -> Option<Self> {
+        let msg = msg.to_string();
+        let err = match code {
+            0 => return None,
+            CODE_INVALID_WEBHOOK => CustomBotError::InvalidWebhook(msg),
+            CODE_BAD_REQUEST => CustomBotError::BadRequest(msg),
+            CODE_BOT_DISABLED => CustomBotError::BotDisabled(msg),
+            CODE_SIGNATURE_MISMATCH => CustomBotError::SignatureMismatch(msg),
+            CODE_IP_NOT_ALLOWED => CustomBotError::IpNotAllowed(msg),
+            CODE_KEYWORD_MISMATCH => CustomBotError::KeywordMismatch(msg),
+            CODE_RATE_LIMITED | CODE_FREQUENCY_LIMITED => CustomBotError::RateLimited(msg),
+            _ => CustomBotError::Other { code, msg },
+        };
+        Some(err)
+    }
+
+    /// 错误码
+    pub fn code(&self) -> i32 {
+        match self {
+            CustomBotError::InvalidWebhook(_) => CODE_INVALID_WEBHOOK,
+            CustomBotError::BadRequest(_) => CODE_BAD_REQUEST,
+            CustomBotError::BotDisabled(_) => CODE_BOT_DISABLED,
+            CustomBotError::SignatureMismatch(_) => CODE_SIGNATURE_MISMATCH,
+            CustomBotError::IpNotAllowed(_) => CODE_IP_NOT_ALLOWED,
+            CustomBotError::KeywordMismatch(_) => CODE_KEYWORD_MISMATCH,
+            CustomBotError::RateLimited(_) => CODE_RATE_LIMITED,
+            CustomBotError::Other { code, .. } => *code,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::custom_bot::error::CustomBotError;
+
+    #[test]
+    fn test_from_code() {
+        assert_eq!(CustomBotError::from_code(0, "success"), None);
+        assert_eq!(
+            CustomBotError::from_code(19021, "sign match fail"),
+            Some(CustomBotError::SignatureMismatch(
+                "sign match fail".to_string()
+            ))
+        );
+        assert_eq!(
+            CustomBotError::from_code(19024, "Key Words Not Found"),
+            Some(CustomBotError::KeywordMismatch(
+                "Key Words Not Found".to_string()
+            ))
+        );
+        assert!(matches!(
+            CustomBotError::from_code(11232, "frequency limited"),
+            Some(CustomBotError::RateLimited(_))
+        ));
+
+        let err = CustomBotError::from_code(12345, "unknown").unwrap();
+        assert_eq!(err.code(), 12345);
+        assert_eq!(err.to_string(), "Webhook error: 12345, unknown");
+    }
+}
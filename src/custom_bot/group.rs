@@ -0,0 +1,301 @@
+use futures_util::future::join_all;
+use serde_json::Value;
+
+use crate::{
+    core::{
+        api_resp::{BaseResponse, RawResponse},
+        config::Config,
+        error::LarkAPIError,
+        req_option::RequestOption,
+        SDKResult,
+    },
+    custom_bot::{error::CustomBotError, CustomBot},
+    service::{
+        common::ReceiveIdType,
+        im::v1::message::{
+            CreateMessageRequest, CreateMessageRequestBody, Message, MessageService,
+            SendMessageTrait,
+        },
+    },
+};
+
+/// 自定义机器人组, 将同一条消息并发发送到多个 webhook
+///
+/// webhook 返回机器人已停用的错误时, 可以改用应用机器人发送到该 webhook 所在的群
+#[derive(Debug, Clone, Default)]
+pub struct CustomBotGroup {
+    targets: Vec<CustomBotTarget>,
+    fallback: Option<Fallback>,
+    /// 改用应用机器人发送时的请求选项
+    fallback_option: Option<RequestOption>,
+}
+
+/// 自定义机器人组中的一个 webhook
+#[derive(Debug, Clone)]
+pub struct CustomBotTarget {
+    /// 名称, 用于区分发送结果
+    name: String,
+    bot: CustomBot,
+    /// 改用应用机器人发送时的群 ID
+    fallback_chat_id: Option<String>,
+}
+
+/// 改用应用机器人发送时的客户端配置
+#[derive(Debug, Clone)]
+struct Fallback {
+    config: Config,
+}
+
+/// 单个 webhook 的发送结果
+#[derive(Debug)]
+pub struct TargetResult {
+    /// webhook 的名称
+    pub name: String,
+    /// 通过 webhook 发送的结果
+    pub result: SDKResult<BaseResponse<RawResponse>>,
+    /// 改用应用机器人发送的结果, 未改用时为 None
+    pub fallback: Option<SDKResult<BaseResponse<Message>>>,
+}
+
+impl TargetResult {
+    /// 通过 webhook 或应用机器人发送成功
+    pub fn is_success(&self) -> bool {
+        match &self.fallback {
+            Some(fallback) => fallback.as_ref().is_ok_and(|resp| resp.success()),
+            None => self.result.is_ok(),
+        }
+    }
+}
+
+impl CustomBotTarget {
+    pub fn new(name: impl ToString, bot: CustomBot) -> Self {
+        Self {
+            name: name.to_string(),
+            bot,
+            fallback_chat_id: None,
+        }
+    }
+
+    /// 机器人已停用时, 改用应用机器人发送到该群, 应用机器人需要在群中
+    pub fn with_fallback_chat_id(mut self, chat_id: impl ToString) -> Self {
+        self.fallback_chat_id = Some(chat_id.to_string());
+        self
+    }
+}
+
+impl CustomBotGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加 webhook, 每个 webhook 使用各自的密钥
+    pub fn add_webhook(
+        self,
+        name: impl ToString,
+        webhook_url: impl ToString,
+        secret: Option<String>,
+    ) -> Self {
+        self.add_bot(name, CustomBot::new(webhook_url.to_string(), secret))
+    }
+
+    /// 添加已创建的自定义机器人, 可以复用其 HTTP 客户端和发送队列
+    pub fn add_bot(self, name: impl ToString, bot: CustomBot) -> Self {
+        self.add_target(CustomBotTarget::new(name, bot))
+    }
+
+    /// 添加 webhook, 可以设置改用应用机器人发送时的群 ID
+    pub fn add_target(mut self, target: CustomBotTarget) -> Self {
+        self.targets.push(target);
+        self
+    }
+
+    /// 机器人已停用时改用应用机器人发送, 仅对设置了群 ID 的 webhook 生效
+    pub fn with_fallback(mut self, config: &Config) -> Self {
+        self.fallback = Some(Fallback {
+            config: config.clone(),
+        });
+        self
+    }
+
+    /// 应用机器人发送时的请求选项, 如商店应用的 tenant_key
+    pub fn with_fallback_option(mut self, option: RequestOption) -> Self {
+        self.fallback_option = Some(option);
+        self
+    }
+
+    /// 并发发送消息, 按添加顺序返回每个 webhook 的发送结果
+    ///
+    /// 仅在消息内容无法解析时返回错误
+    pub async fn send_message(
+        &self,
+        message: impl SendMessageTrait,
+    ) -> SDKResult<Vec<TargetResult>> {
+        let body = CustomBot::webhook_body(&message)?;
+        let msg_type = message.msg_type();
+        let content = message.content();
+
+        let results = join_all(
+            self.targets
+                .iter()
+                .map(|target| self.send_target(target, &body, &msg_type, &content)),
+        )
+        .await;
+
+        Ok(results)
+    }
+
+    async fn send_target(
+        &self,
+        target: &CustomBotTarget,
+        body: &Value,
+        msg_type: &str,
+        content: &str,
+    ) -> TargetResult {
+        let result = target.bot.send(body.clone()).await;
+
+        let fallback = match (&result, &self.fallback, &target.fallback_chat_id) {
+            (
+                Err(LarkAPIError::CustomBotError(CustomBotError::BotDisabled(_))),
+                Some(fallback),
+                Some(chat_id),
+            ) => Some(
+                fallback
+                    .send(chat_id, msg_type, content, self.fallback_option.clone())
+                    .await,
+            ),
+            _ => None,
+        };
+
+        TargetResult {
+            name: target.name.clone(),
+            result,
+            fallback,
+        }
+    }
+}
+
+impl Fallback {
+    async fn send(
+        &self,
+        chat_id: &str,
+        msg_type: &str,
+        content: &str,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<Message>> {
+        let service = MessageService {
+            config: self.config.clone(),
+        };
+        let req = CreateMessageRequest::builder()
+            .receive_id_type(ReceiveIdType::ChatId)
+            .request_body(
+                CreateMessageRequestBody::builder()
+                    .receive_id(chat_id)
+                    .msg_type(msg_type)
+                    .content(content)
+                    .build(),
+            )
+            .build();
+
+        service.create(req, option).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::{
+        core::{config::Config, constants::Domain, error::LarkAPIError, req_option::RequestOption},
+        custom_bot::{
+            error::CustomBotError,
+            group::{CustomBotGroup, CustomBotTarget},
+            test::webhook,
+            CustomBot,
+        },
+        service::im::v1::message::MessageText,
+    };
+
+    #[tokio::test]
+    async fn test_fan_out_and_fallback() {
+        let success = json!({"code": 0, "msg": "success", "data": {}});
+        let disabled = json!({"code": 19007, "msg": "Bot Not Enabled", "data": {}});
+        let message = json!({
+            "code": 0,
+            "msg": "success",
+            "data": {
+                "message_id": "om_dc13264520392913993dd051dba21dcf",
+                "msg_type": "text",
+                "create_time": "1615380573411",
+                "update_time": "1615380573411",
+                "deleted": false,
+                "updated": false,
+                "chat_id": "oc_5ad11d72b830411d72b836c20",
+                "sender": {
+                    "id": "cli_9f427eec54ae901b",
+                    "id_type": "app_id",
+                    "sender_type": "app",
+                    "tenant_key": "736588c9260f175e"
+                },
+                "body": {"content": "{\"text\":\"告警\"}"}
+            }
+        });
+
+        let (ops_url, ops_bodies) = webhook(vec![success]).await;
+        let (dev_url, dev_bodies) = webhook(vec![disabled.clone(), message]).await;
+        let (qa_url, qa_bodies) = webhook(vec![disabled]).await;
+        let config = Config {
+            app_id: "cli_9f427eec54ae901b".to_string(),
+            app_secret: "app_secret".into(),
+            domain: Domain::Custom(dev_url.split("/open-apis").next().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let group = CustomBotGroup::new()
+            .add_webhook("ops", ops_url, Some("ops_secret".to_string()))
+            .add_target(
+                CustomBotTarget::new("dev", CustomBot::new(dev_url, None))
+                    .with_fallback_chat_id("oc_5ad11d72b830411d72b836c20"),
+            )
+            .add_bot("qa", CustomBot::new(qa_url, None))
+            // 请求选项与客户端配置的设置顺序无关
+            .with_fallback_option(RequestOption::builder().user_access_token("u-xxx").build())
+            .with_fallback(&config);
+
+        let results = group.send_message(MessageText::new("告警")).await.unwrap();
+        assert_eq!(
+            results.iter().map(|r| r.name.as_str()).collect::<Vec<_>>(),
+            ["ops", "dev", "qa"]
+        );
+
+        assert!(results[0].is_success());
+        assert!(results[0].fallback.is_none());
+        assert!(ops_bodies.lock().unwrap()[0]["sign"].is_string());
+
+        assert!(results[1].is_success());
+        let resp = results[1].fallback.as_ref().unwrap().as_ref().unwrap();
+        assert_eq!(
+            resp.data.as_ref().unwrap().message_id,
+            "om_dc13264520392913993dd051dba21dcf"
+        );
+        let dev_bodies = dev_bodies.lock().unwrap();
+        assert!(dev_bodies[0].get("sign").is_none());
+        assert_eq!(
+            dev_bodies[1],
+            json!({
+                "receive_id": "oc_5ad11d72b830411d72b836c20",
+                "msg_type": "text",
+                "content": "{\"text\":\"告警\"}",
+                "uuid": null
+            })
+        );
+
+        // 未设置群 ID 时不改用应用机器人
+        assert!(!results[2].is_success());
+        assert!(results[2].fallback.is_none());
+        assert!(matches!(
+            results[2].result,
+            Err(LarkAPIError::CustomBotError(CustomBotError::BotDisabled(_)))
+        ));
+        assert_eq!(qa_bodies.lock().unwrap().len(), 1);
+    }
+}
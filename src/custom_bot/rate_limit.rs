@@ -0,0 +1,129 @@
+use std::{collections::VecDeque, time::Duration};
+
+use tokio::{sync::Mutex, time::Instant};
+
+/// 自定义机器人的频率限制
+///
+/// webhook 对单个机器人限制为 100 次/分钟、5 次/秒, 超出后返回频率超限错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// 每秒最多请求数
+    pub per_second: usize,
+    /// 每分钟最多请求数
+    pub per_minute: usize,
+    /// 频率超限时的最大重试次数
+    pub max_retries: u32,
+    /// 重试的基础等待时长, 第 n 次重试等待 n 倍
+    pub retry_backoff: Duration,
+}
+
+impl Default for RateLimit {
+    fn default() -> Self {
+        Self {
+            per_second: 5,
+            per_minute: 100,
+            max_retries: 3,
+            retry_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+/// 按发送顺序排队, 保证请求频率在限制内
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    /// 最近一分钟内的请求时间
+    sent: Mutex<VecDeque<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            sent: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    pub fn limit(&self) -> &RateLimit {
+        &self.limit
+    }
+
+    /// 等待到可以发送下一个请求, 等待期间持有锁, 后续请求按顺序排队
+    pub async fn acquire(&self) {
+        let mut sent = self.sent.lock().await;
+        loop {
+            let now = Instant::now();
+            while sent
+                .front()
+                .is_some_and(|t| now.duration_since(*t) >= Duration::from_secs(60))
+            {
+                sent.pop_front();
+            }
+
+            let wait = self.wait_time(&sent, now);
+            if wait.is_zero() {
+                sent.push_back(now);
+                return;
+            }
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    fn wait_time(&self, sent: &VecDeque<Instant>, now: Instant) -> Duration {
+        let mut wait = Duration::ZERO;
+        if sent.len() >= self.limit.per_minute.max(1) {
+            let oldest = sent[sent.len() - self.limit.per_minute.max(1)];
+            wait = wait.max(Duration::from_secs(60).saturating_sub(now - oldest));
+        }
+        let per_second = self.limit.per_second.max(1);
+        let in_last_second = sent
+            .iter()
+            .rev()
+            .take_while(|t| now.duration_since(**t) < Duration::from_secs(1))
+            .count();
+        if in_last_second >= per_second {
+            let oldest = sent[sent.len() - per_second];
+            wait = wait.max(Duration::from_secs(1).saturating_sub(now - oldest));
+        }
+        wait
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use crate::custom_bot::rate_limit::{RateLimit, RateLimiter};
+
+    #[tokio::test(start_paused = true)]
+    async fn test_per_second_limit() {
+        let limiter = RateLimiter::new(RateLimit::default());
+        let start = Instant::now();
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        assert_eq!(start.elapsed(), Duration::ZERO);
+
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_per_minute_limit() {
+        let limiter = RateLimiter::new(RateLimit {
+            per_second: 10,
+            per_minute: 20,
+            ..Default::default()
+        });
+        let start = Instant::now();
+        for _ in 0..20 {
+            limiter.acquire().await;
+        }
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+
+        limiter.acquire().await;
+        assert_eq!(start.elapsed(), Duration::from_secs(60));
+    }
+}
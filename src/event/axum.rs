@@ -0,0 +1,143 @@
+//! axum 适配, 需要开启 `axum` feature
+//!
+//! ```ignore
+//! use open_lark::event::{axum::event_router, EventDispatcher};
+//!
+//! let dispatcher = EventDispatcher::new()
+//!     .with_config(&client.config)
+//!     .on_message_receive_v1(|event| async move { Ok(()) })
+//!     .on_card_action_trigger(|event| async move { Ok(CardActionResponse::new()) });
+//! let app = event_router("/webhook/event", dispatcher);
+//! ```
+use ::axum::{
+    body::Bytes,
+    extract::State,
+    http::HeaderMap,
+    response::{IntoResponse, Response},
+    routing::post,
+    Router,
+};
+
+use crate::event::{webhook::EventRequest, EventDispatcher};
+
+/// 处理事件与卡片回传交互回调, 通过 `State` 获取分发器
+pub async fn event_handler(
+    State(dispatcher): State<EventDispatcher>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let resp = dispatcher
+        .handle(EventRequest::from_headers(&headers, body.to_vec()))
+        .await;
+    (resp.status(), resp.header_map(), resp.body).into_response()
+}
+
+/// 在指定路径上接收事件回调的路由
+pub fn event_router(path: &str, dispatcher: EventDispatcher) -> Router {
+    Router::new()
+        .route(path, post(event_handler))
+        .with_state(dispatcher)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use ::axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    use crate::event::{
+        axum::event_router,
+        webhook::{
+            test::{ENCRYPTED_EVENT, ENCRYPT_KEY, EVENT_NONCE, EVENT_SIGNATURE, EVENT_TIMESTAMP},
+            HEADER_REQUEST_NONCE, HEADER_REQUEST_TIMESTAMP, HEADER_SIGNATURE,
+        },
+        EventDispatcher, EventV2,
+    };
+
+    #[tokio::test]
+    async fn test_url_verification() {
+        let app = event_router(
+            "/webhook/event",
+            EventDispatcher::new().with_verification_token("token"),
+        );
+        let body =
+            json!({"challenge": "ajls384kdjx98XX", "token": "token", "type": "url_verification"});
+        let resp = app
+            .oneshot(
+                Request::post("/webhook/event")
+                    .header("Content-Type", "application/json")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), 200);
+        let body = resp.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body, json!({"challenge": "ajls384kdjx98XX"}));
+    }
+
+    #[tokio::test]
+    async fn test_rejected_request() {
+        let app = event_router(
+            "/webhook/event",
+            EventDispatcher::new().with_verification_token("token"),
+        );
+        let body =
+            json!({"challenge": "ajls384kdjx98XX", "token": "wrong", "type": "url_verification"});
+        let resp = app
+            .oneshot(
+                Request::post("/webhook/event")
+                    .body(Body::from(body.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), 401);
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_event() {
+        let chat_id = Arc::new(Mutex::new(String::new()));
+        let received = Arc::clone(&chat_id);
+        let dispatcher = EventDispatcher::new()
+            .with_encrypt_key(ENCRYPT_KEY)
+            .with_verification_token("xxxxxx")
+            .register("im.chat.disbanded_v1", move |event: EventV2<Value>| {
+                let received = Arc::clone(&received);
+                async move {
+                    *received.lock().unwrap() = event.event["chat_id"].as_str().unwrap().into();
+                    Ok(())
+                }
+            });
+        let app = event_router("/webhook/event", dispatcher);
+        let resp = app
+            .oneshot(
+                Request::post("/webhook/event")
+                    .header(HEADER_REQUEST_TIMESTAMP, EVENT_TIMESTAMP)
+                    .header(HEADER_REQUEST_NONCE, EVENT_NONCE)
+                    .header(HEADER_SIGNATURE, EVENT_SIGNATURE)
+                    .body(Body::from(
+                        json!({ "encrypt": ENCRYPTED_EVENT }).to_string(),
+                    ))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            resp.headers()["content-type"],
+            "application/json; charset=utf-8"
+        );
+        assert_eq!(
+            *chat_id.lock().unwrap(),
+            "oc_a0553eda9014c201e6969b478895c230"
+        );
+    }
+}
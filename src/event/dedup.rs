@@ -0,0 +1,197 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use futures_util::future::{self, BoxFuture, FutureExt};
+
+/// 默认的去重时长, 覆盖开放平台的重推周期
+pub const DEFAULT_DEDUP_TTL: Duration = Duration::from_secs(12 * 60 * 60);
+/// 本地去重存储默认最多保留的事件数
+pub const DEFAULT_DEDUP_CAPACITY: usize = 10_000;
+
+/// 已处理事件的存储, 用于事件去重
+///
+/// 方法均为异步且只需 `&self`, 可以对接 Redis 等外部存储, `set` 对应 `SET event_id 1 NX EX ttl`
+pub trait EventStore: Send + Sync {
+    /// 记录事件, 事件尚未记录或已过期时返回 true, 检查与写入需要是原子的
+    fn set<'a>(&'a self, event_id: &'a str, expire_time: Duration) -> BoxFuture<'a, bool>;
+    fn contains<'a>(&'a self, event_id: &'a str) -> BoxFuture<'a, bool>;
+    fn remove<'a>(&'a self, event_id: &'a str) -> BoxFuture<'a, ()>;
+}
+
+/// 本地内存去重存储, 超过容量时淘汰最早的事件
+pub struct LocalEventStore {
+    capacity: usize,
+    inner: Mutex<LocalEvents>,
+}
+
+#[derive(Default)]
+struct LocalEvents {
+    /// 事件的过期时间和记录时的代数
+    events: HashMap<String, (Instant, u64)>,
+    /// 按记录顺序排列的事件, 代数不匹配的条目已被移除或重新记录
+    order: VecDeque<(String, u64)>,
+    generation: u64,
+}
+
+impl Default for LocalEventStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_DEDUP_CAPACITY)
+    }
+}
+
+impl LocalEventStore {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(LocalEvents::default()),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lock().events.is_empty()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LocalEvents> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn insert(&self, event_id: &str, expire_time: Duration) -> bool {
+        let now = Instant::now();
+        let mut inner = self.lock();
+        if inner
+            .events
+            .get(event_id)
+            .is_some_and(|(expire_at, _)| *expire_at > now)
+        {
+            return false;
+        }
+
+        inner.generation += 1;
+        let generation = inner.generation;
+        inner
+            .events
+            .insert(event_id.to_string(), (now + expire_time, generation));
+        inner.order.push_back((event_id.to_string(), generation));
+        inner.evict(self.capacity, now);
+        true
+    }
+
+    fn contains_now(&self, event_id: &str) -> bool {
+        self.lock()
+            .events
+            .get(event_id)
+            .is_some_and(|(expire_at, _)| *expire_at > Instant::now())
+    }
+
+    fn remove_now(&self, event_id: &str) {
+        let mut inner = self.lock();
+        // 队列中的条目留到淘汰时跳过
+        inner.events.remove(event_id);
+        if inner.order.len() > 2 * self.capacity.max(1) {
+            inner.compact();
+        }
+    }
+}
+
+impl LocalEvents {
+    fn is_live(&self, event_id: &str, generation: u64) -> bool {
+        self.events
+            .get(event_id)
+            .is_some_and(|(_, g)| *g == generation)
+    }
+
+    /// 清理过期事件, 并在超出容量时淘汰最早的事件
+    fn evict(&mut self, capacity: usize, now: Instant) {
+        while let Some((event_id, generation)) = self.order.front() {
+            match self.events.get(event_id) {
+                Some((expire_at, g)) if g == generation => {
+                    if *expire_at > now && self.events.len() <= capacity {
+                        break;
+                    }
+                    self.events.remove(event_id);
+                }
+                // 已移除或重新记录的事件
+                _ => {}
+            }
+            self.order.pop_front();
+        }
+    }
+
+    /// 丢弃队列中已失效的条目, 摊还到每次移除上仍为 O(1)
+    fn compact(&mut self) {
+        let order = std::mem::take(&mut self.order);
+        self.order = order
+            .into_iter()
+            .filter(|(event_id, generation)| self.is_live(event_id, *generation))
+            .collect();
+    }
+}
+
+impl EventStore for LocalEventStore {
+    fn set<'a>(&'a self, event_id: &'a str, expire_time: Duration) -> BoxFuture<'a, bool> {
+        future::ready(self.insert(event_id, expire_time)).boxed()
+    }
+
+    fn contains<'a>(&'a self, event_id: &'a str) -> BoxFuture<'a, bool> {
+        future::ready(self.contains_now(event_id)).boxed()
+    }
+
+    fn remove<'a>(&'a self, event_id: &'a str) -> BoxFuture<'a, ()> {
+        self.remove_now(event_id);
+        future::ready(()).boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use crate::event::dedup::{EventStore, LocalEventStore};
+
+    #[tokio::test]
+    async fn test_local_event_store() {
+        let store = LocalEventStore::new(2);
+        assert!(store.set("event_1", Duration::from_secs(60)).await);
+        assert!(!store.set("event_1", Duration::from_secs(60)).await);
+        store.set("event_2", Duration::from_secs(60)).await;
+        assert!(store.contains("event_1").await);
+
+        store.set("event_3", Duration::from_secs(60)).await;
+        assert_eq!(store.len(), 2);
+        assert!(!store.contains("event_1").await);
+        assert!(store.contains("event_3").await);
+
+        store.set("event_4", Duration::ZERO).await;
+        assert!(!store.contains("event_4").await);
+    }
+
+    #[tokio::test]
+    async fn test_local_event_store_remove() {
+        let store = LocalEventStore::new(2);
+        store.set("event_1", Duration::from_secs(60)).await;
+        store.set("event_2", Duration::from_secs(60)).await;
+        store.remove("event_1").await;
+        assert!(!store.contains("event_1").await);
+
+        // 重新记录后, 队列中旧的条目不会把它提前淘汰
+        assert!(store.set("event_1", Duration::from_secs(60)).await);
+        store.set("event_3", Duration::from_secs(60)).await;
+        assert_eq!(store.len(), 2);
+        assert!(store.contains("event_1").await);
+        assert!(!store.contains("event_2").await);
+
+        for _ in 0..10 {
+            store.remove("event_1").await;
+            store.set("event_1", Duration::from_secs(60)).await;
+        }
+        assert!(store.lock().order.len() <= 4);
+        assert!(store.contains("event_3").await);
+    }
+}
@@ -0,0 +1,177 @@
+//! hyper 适配, 需要开启 `hyper` feature
+//!
+//! ```ignore
+//! use hyper::server::conn::http1;
+//! use open_lark::event::{hyper::EventService, EventDispatcher};
+//!
+//! let service = EventService::new(EventDispatcher::new().with_config(&client.config));
+//! http1::Builder::new().serve_connection(io, service).await?;
+//! ```
+use std::convert::Infallible;
+
+use ::hyper::{
+    body::{Body, Bytes},
+    service::Service,
+    Request, Response,
+};
+use futures_util::future::BoxFuture;
+use http_body_util::{BodyExt, Full};
+use serde_json::json;
+
+use crate::event::{
+    webhook::{EventRequest, EventResponse},
+    EventDispatcher,
+};
+
+/// 处理事件与卡片回传交互回调, 不区分请求路径
+pub async fn handle_request<B>(
+    dispatcher: &EventDispatcher,
+    req: Request<B>,
+) -> Response<Full<Bytes>>
+where
+    B: Body,
+{
+    let (parts, body) = req.into_parts();
+    let body = match body.collect().await {
+        Ok(body) => body.to_bytes(),
+        Err(_) => {
+            return into_response(EventResponse::json(
+                400,
+                &json!({ "msg": "failed to read body" }),
+            ))
+        }
+    };
+
+    let req = EventRequest::from_headers(&parts.headers, body.to_vec());
+    into_response(dispatcher.handle(req).await)
+}
+
+/// 事件回调的 hyper [`Service`]
+#[derive(Debug, Clone)]
+pub struct EventService {
+    dispatcher: EventDispatcher,
+}
+
+impl EventService {
+    pub fn new(dispatcher: EventDispatcher) -> Self {
+        Self { dispatcher }
+    }
+}
+
+impl<B> Service<Request<B>> for EventService
+where
+    B: Body + Send + 'static,
+    B::Data: Send,
+{
+    type Response = Response<Full<Bytes>>;
+    type Error = Infallible;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn call(&self, req: Request<B>) -> Self::Future {
+        let dispatcher = self.dispatcher.clone();
+        Box::pin(async move { Ok(handle_request(&dispatcher, req).await) })
+    }
+}
+
+fn into_response(resp: EventResponse) -> Response<Full<Bytes>> {
+    let status = resp.status();
+    let headers = resp.header_map();
+    let mut response = Response::new(Full::new(Bytes::from(resp.body)));
+    *response.status_mut() = status;
+    *response.headers_mut() = headers;
+    response
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use ::hyper::{body::Bytes, service::Service, Request};
+    use http_body_util::{BodyExt, Full};
+    use serde_json::{json, Value};
+
+    use crate::event::{
+        hyper::EventService,
+        webhook::{
+            test::{ENCRYPTED_EVENT, ENCRYPT_KEY, EVENT_NONCE, EVENT_SIGNATURE, EVENT_TIMESTAMP},
+            HEADER_REQUEST_NONCE, HEADER_REQUEST_TIMESTAMP, HEADER_SIGNATURE,
+        },
+        EventDispatcher, EventV2,
+    };
+
+    #[tokio::test]
+    async fn test_event_service() {
+        let service = EventService::new(EventDispatcher::new());
+        let body = json!({"schema": "2.0", "header": {"event_type": "unknown"}, "event": {}});
+        let resp = service
+            .call(
+                Request::post("/")
+                    .body(Full::new(Bytes::from(body.to_string())))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // 未注册的事件返回成功
+        assert_eq!(resp.status(), 200);
+        let body = resp.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body, json!({"msg": "success"}));
+
+        let body = json!({"schema": "2.0", "header": {}, "event": {}});
+        let resp = service
+            .call(
+                Request::post("/")
+                    .body(Full::new(Bytes::from(body.to_string())))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), 500);
+        let body = resp.into_body().collect().await.unwrap().to_bytes();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            body,
+            json!({"msg": "Event error: missing header.event_type"})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_event() {
+        let chat_id = Arc::new(Mutex::new(String::new()));
+        let received = Arc::clone(&chat_id);
+        let dispatcher = EventDispatcher::new()
+            .with_encrypt_key(ENCRYPT_KEY)
+            .with_verification_token("xxxxxx")
+            .register("im.chat.disbanded_v1", move |event: EventV2<Value>| {
+                let received = Arc::clone(&received);
+                async move {
+                    *received.lock().unwrap() = event.event["chat_id"].as_str().unwrap().into();
+                    Ok(())
+                }
+            });
+        let service = EventService::new(dispatcher);
+        let request = |signature: &str| {
+            Request::post("/")
+                .header(HEADER_REQUEST_TIMESTAMP, EVENT_TIMESTAMP)
+                .header(HEADER_REQUEST_NONCE, EVENT_NONCE)
+                .header(HEADER_SIGNATURE, signature)
+                .body(Full::new(Bytes::from(
+                    json!({ "encrypt": ENCRYPTED_EVENT }).to_string(),
+                )))
+                .unwrap()
+        };
+
+        let resp = service.call(request(EVENT_SIGNATURE)).await.unwrap();
+        assert_eq!(resp.status(), 200);
+        assert_eq!(
+            *chat_id.lock().unwrap(),
+            "oc_a0553eda9014c201e6969b478895c230"
+        );
+
+        // 签名错误
+        let resp = service.call(request("invalid signature")).await.unwrap();
+        assert_eq!(resp.status(), 401);
+    }
+}
@@ -0,0 +1,417 @@
+//! 事件订阅
+//!
+//! [`EventDispatcher`] 按事件类型注册处理函数, 同一个分发器可以同时用于 Webhook 回调和长连接。
+//!
+//! ```no_run
+//! use open_lark::event::{EventDispatcher, EventV2};
+//! use serde_json::Value;
+//!
+//! let dispatcher = EventDispatcher::new().register(
+//!     "im.message.receive_v1",
+//!     |event: EventV2<Value>| async move {
+//!         println!("{:?}", event.event);
+//!         Ok(())
+//!     },
+//! );
+//! ```
+use std::{
+    collections::HashMap,
+    fmt::Debug,
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use futures_util::future::BoxFuture;
+use log::{debug, warn};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::core::{config::Config, error::LarkAPIError, secret::Secret, SDKResult};
+
+use crate::event::{
+    dedup::{EventStore, LocalEventStore, DEFAULT_DEDUP_TTL},
+    webhook::DEFAULT_RESPONSE_TIMEOUT,
+};
+
+#[cfg(feature = "axum")]
+pub mod axum;
+
+pub mod dedup;
+pub mod v1;
+pub mod webhook;
+
+#[cfg(feature = "hyper")]
+pub mod hyper;
+
+/// 事件处理函数, 返回值作为回调的响应体
+type EventHandler =
+    Arc<dyn Fn(Value) -> BoxFuture<'static, SDKResult<Option<Value>>> + Send + Sync>;
+
+/// 已注册的处理函数
+#[derive(Clone)]
+struct RegisteredHandler {
+    handler: EventHandler,
+    /// 是否返回响应体
+    with_response: bool,
+}
+
+/// 事件头
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventHeader {
+    /// 事件 ID, 可用于去重
+    pub event_id: String,
+    /// 事件类型
+    pub event_type: String,
+    /// 事件发送的时间, 毫秒时间戳
+    pub create_time: String,
+    /// 事件的 Verification Token
+    pub token: String,
+    /// 应用 ID
+    pub app_id: String,
+    /// 租户 Key
+    pub tenant_key: String,
+}
+
+/// 2.0 版本的事件结构
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventV2<T> {
+    /// 事件格式的版本, 2.0 或由 1.0 版本转换而来时为 1.0
+    pub schema: String,
+    pub header: EventHeader,
+    /// 事件的详细数据
+    pub event: T,
+}
+
+/// 事件分发器
+///
+/// 默认按事件 ID 在本地内存中去重, 克隆出的分发器共享同一个去重存储。
+#[derive(Clone)]
+pub struct EventDispatcher {
+    handlers: HashMap<String, RegisteredHandler>,
+    /// 事件订阅的 Verification Token, 设置后校验事件中的 token
+    verification_token: Option<Secret>,
+    /// 事件订阅的 Encrypt Key, 设置后校验签名并解密事件
+    encrypt_key: Option<Secret>,
+    /// 去重存储, 为空时不去重
+    event_store: Option<Arc<dyn EventStore>>,
+    dedup_ttl: Duration,
+    /// Webhook 的响应时限, 超时后处理函数转入后台执行, 带响应体的处理函数除外
+    response_timeout: Duration,
+    /// 被丢弃的重复事件数
+    duplicate_count: Arc<AtomicU64>,
+}
+
+impl Default for EventDispatcher {
+    fn default() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            verification_token: None,
+            encrypt_key: None,
+            event_store: Some(Arc::new(LocalEventStore::default())),
+            dedup_ttl: DEFAULT_DEDUP_TTL,
+            response_timeout: DEFAULT_RESPONSE_TIMEOUT,
+            duplicate_count: Arc::new(AtomicU64::new(0)),
+        }
+    }
+}
+
+impl Debug for EventDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventDispatcher")
+            .field("event_types", &self.handlers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl EventDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 使用客户端配置中的 Verification Token 与 Encrypt Key
+    pub fn with_config(mut self, config: &Config) -> Self {
+        self.verification_token = config.verification_token.clone();
+        self.encrypt_key = config.encrypt_key.clone();
+        self
+    }
+
+    pub fn with_verification_token(mut self, verification_token: impl ToString) -> Self {
+        self.verification_token = Some(verification_token.to_string().into());
+        self
+    }
+
+    pub fn with_encrypt_key(mut self, encrypt_key: impl ToString) -> Self {
+        self.encrypt_key = Some(encrypt_key.to_string().into());
+        self
+    }
+
+    /// 使用自定义的去重存储, 如 Redis
+    pub fn with_event_store(mut self, event_store: impl EventStore + 'static) -> Self {
+        self.event_store = Some(Arc::new(event_store));
+        self
+    }
+
+    /// 设置去重时长, 默认 12 小时
+    pub fn with_dedup_ttl(mut self, dedup_ttl: Duration) -> Self {
+        self.dedup_ttl = dedup_ttl;
+        self
+    }
+
+    /// 设置 Webhook 的响应时限, 默认 2.5 秒
+    ///
+    /// 超时后先应答成功, 处理函数转入后台执行; 通过 [`Self::register_with_response`] 注册的处理函数不受影响
+    pub fn with_response_timeout(mut self, response_timeout: Duration) -> Self {
+        self.response_timeout = response_timeout;
+        self
+    }
+
+    /// 关闭事件去重
+    pub fn without_dedup(mut self) -> Self {
+        self.event_store = None;
+        self
+    }
+
+    /// 已丢弃的重复事件数
+    pub fn duplicate_count(&self) -> u64 {
+        self.duplicate_count.load(Ordering::Relaxed)
+    }
+
+    /// 注册事件处理函数, 同一事件类型重复注册时后者生效
+    ///
+    /// - event_type: 事件类型, 如 `im.message.receive_v1`
+    pub fn register<T, F, Fut>(mut self, event_type: impl ToString, handler: F) -> Self
+    where
+        T: DeserializeOwned + Send + 'static,
+        F: Fn(EventV2<T>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        let handler = Arc::new(handler);
+        self.handlers.insert(
+            event_type.to_string(),
+            RegisteredHandler {
+                handler: Arc::new(move |value: Value| {
+                    let handler = Arc::clone(&handler);
+                    Box::pin(async move {
+                        let event = serde_json::from_value::<EventV2<T>>(value)?;
+                        handler(event).await?;
+                        Ok(None)
+                    })
+                }),
+                with_response: false,
+            },
+        );
+        self
+    }
+
+    /// 注册带响应体的处理函数, 如卡片回传交互, 返回值序列化后作为回调的响应体
+    ///
+    /// Webhook 会等待该处理函数返回后再应答, 不受响应时限的限制,
+    /// 处理函数需要自行在 3 秒内返回, 否则平台视为回调失败
+    pub fn register_with_response<T, R, F, Fut>(
+        mut self,
+        event_type: impl ToString,
+        handler: F,
+    ) -> Self
+    where
+        T: DeserializeOwned + Send + 'static,
+        R: Serialize,
+        F: Fn(EventV2<T>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<R>> + Send + 'static,
+    {
+        let handler = Arc::new(handler);
+        self.handlers.insert(
+            event_type.to_string(),
+            RegisteredHandler {
+                handler: Arc::new(move |value: Value| {
+                    let handler = Arc::clone(&handler);
+                    Box::pin(async move {
+                        let event = serde_json::from_value::<EventV2<T>>(value)?;
+                        let resp = handler(event).await?;
+                        Ok(Some(serde_json::to_value(resp)?))
+                    })
+                }),
+                with_response: true,
+            },
+        );
+        self
+    }
+
+    /// 是否注册了该事件类型
+    pub fn contains(&self, event_type: &str) -> bool {
+        self.handlers.contains_key(event_type)
+    }
+
+    /// 该事件类型的处理函数是否返回响应体
+    pub(crate) fn has_response(&self, event_type: &str) -> bool {
+        self.handlers
+            .get(event_type)
+            .is_some_and(|handler| handler.with_response)
+    }
+
+    /// 解析事件并调用对应的处理函数
+    ///
+    /// 返回处理函数给出的响应体, 没有响应体时为 `None`
+    pub async fn dispatch(&self, payload: &[u8]) -> SDKResult<Option<Value>> {
+        let value = serde_json::from_slice::<Value>(payload)?;
+        self.dispatch_value(value).await
+    }
+
+    /// 1.0 版本的事件会先转换为 2.0 结构
+    pub async fn dispatch_value(&self, value: Value) -> SDKResult<Option<Value>> {
+        let value = v1::normalize(value);
+        let event_type = value["header"]["event_type"]
+            .as_str()
+            .ok_or_else(|| LarkAPIError::EventError("missing header.event_type".to_string()))?
+            .to_string();
+
+        match self.handlers.get(&event_type) {
+            Some(RegisteredHandler { handler, .. }) => {
+                let event_id = value["header"]["event_id"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string();
+                if self.is_duplicate(&event_id).await {
+                    debug!("drop duplicate event: {event_type} {event_id}");
+                    return Ok(None);
+                }
+
+                debug!("dispatch event: {event_type}");
+                let resp = handler(value).await;
+                // 处理失败时允许平台重推
+                if resp.is_err() {
+                    self.forget(&event_id).await;
+                }
+                resp
+            }
+            // 未注册的事件也返回成功, 避免平台重复推送
+            None => {
+                warn!("no handler registered for event: {event_type}");
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl EventDispatcher {
+    /// 检查事件是否已处理过, 未处理过时记录下来
+    async fn is_duplicate(&self, event_id: &str) -> bool {
+        let Some(event_store) = self.event_store.as_ref() else {
+            return false;
+        };
+        if event_id.is_empty() {
+            return false;
+        }
+
+        if event_store.set(event_id, self.dedup_ttl).await {
+            return false;
+        }
+        self.duplicate_count.fetch_add(1, Ordering::Relaxed);
+        true
+    }
+
+    async fn forget(&self, event_id: &str) {
+        if let Some(event_store) = self.event_store.as_ref() {
+            if !event_id.is_empty() {
+                event_store.remove(event_id).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use serde::Deserialize;
+
+    use crate::{
+        core::error::LarkAPIError,
+        event::{EventDispatcher, EventV2},
+    };
+
+    #[derive(Debug, Deserialize)]
+    struct ChatDisbanded {
+        chat_id: String,
+    }
+
+    const PAYLOAD: &str = r#"{
+        "schema": "2.0",
+        "header": {
+            "event_id": "5e3702a84e847582be8db7fb73283c02",
+            "event_type": "im.chat.disbanded_v1",
+            "create_time": "1608725989000",
+            "token": "rvaYgkND1GOiu5MM0E1rncYC6PLtF7JV",
+            "app_id": "cli_9f5343c580712544",
+            "tenant_key": "2ca1d211f64f6438"
+        },
+        "event": {"chat_id": "oc_413871f9ac1b2e8e4b3b4b1ac1b2b1a1"}
+    }"#;
+
+    #[tokio::test]
+    async fn test_dispatch() {
+        let received = Arc::new(Mutex::new(vec![]));
+        let sink = Arc::clone(&received);
+        let dispatcher = EventDispatcher::new().register(
+            "im.chat.disbanded_v1",
+            move |event: EventV2<ChatDisbanded>| {
+                let sink = Arc::clone(&sink);
+                async move {
+                    assert_eq!(event.header.tenant_key, "2ca1d211f64f6438");
+                    sink.lock().unwrap().push(event.event.chat_id);
+                    Ok(())
+                }
+            },
+        );
+
+        let resp = dispatcher.dispatch(PAYLOAD.as_bytes()).await.unwrap();
+        assert!(resp.is_none());
+        assert_eq!(
+            *received.lock().unwrap(),
+            vec!["oc_413871f9ac1b2e8e4b3b4b1ac1b2b1a1".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_duplicate_event() {
+        let count = Arc::new(Mutex::new(0));
+        let counter = Arc::clone(&count);
+        let dispatcher = EventDispatcher::new().register(
+            "im.chat.disbanded_v1",
+            move |_: EventV2<ChatDisbanded>| {
+                let counter = Arc::clone(&counter);
+                async move {
+                    *counter.lock().unwrap() += 1;
+                    Ok(())
+                }
+            },
+        );
+
+        dispatcher.dispatch(PAYLOAD.as_bytes()).await.unwrap();
+        dispatcher
+            .clone()
+            .dispatch(PAYLOAD.as_bytes())
+            .await
+            .unwrap();
+        assert_eq!(*count.lock().unwrap(), 1);
+        assert_eq!(dispatcher.duplicate_count(), 1);
+
+        let dispatcher = dispatcher.without_dedup();
+        dispatcher.dispatch(PAYLOAD.as_bytes()).await.unwrap();
+        assert_eq!(*count.lock().unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_unknown_event() {
+        let dispatcher = EventDispatcher::new();
+        let resp = dispatcher.dispatch(PAYLOAD.as_bytes()).await.unwrap();
+        assert!(resp.is_none());
+
+        let err = dispatcher.dispatch(br#"{"header": {}}"#).await.unwrap_err();
+        assert!(matches!(err, LarkAPIError::EventError(_)));
+    }
+}
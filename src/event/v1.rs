@@ -0,0 +1,155 @@
+//! 1.0 版本事件的兼容处理
+//!
+//! 部分事件（如 `app_ticket`、`app_open` 以及旧版订阅的通讯录事件）仍以 1.0 结构推送:
+//!
+//! ```json
+//! {"uuid": "...", "token": "...", "ts": "1502199207.7171419", "type": "event_callback", "event": {"type": "app_ticket", ...}}
+//! ```
+//!
+//! 分发前统一转换为 2.0 结构, `uuid` 作为事件 ID, `event.type` 作为事件类型,
+//! 处理函数都以 [`EventV2`] 接收事件。
+use std::future::Future;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    core::SDKResult,
+    event::{EventDispatcher, EventV2},
+};
+
+/// 事件格式的版本
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventSchema {
+    V1,
+    V2,
+}
+
+impl EventSchema {
+    /// 2.0 版本带有 `schema` 字段, 1.0 版本带有 `uuid` 和 `event.type`
+    pub fn detect(value: &Value) -> Option<Self> {
+        if value["schema"].is_string() && value["header"].is_object() {
+            Some(EventSchema::V2)
+        } else if value["event"]["type"].is_string() {
+            Some(EventSchema::V1)
+        } else {
+            None
+        }
+    }
+}
+
+/// 将 1.0 版本的事件转换为 2.0 结构, 其他事件原样返回
+pub fn normalize(value: Value) -> Value {
+    if EventSchema::detect(&value) != Some(EventSchema::V1) {
+        return value;
+    }
+
+    let event = &value["event"];
+    json!({
+        "schema": "1.0",
+        "header": {
+            "event_id": value["uuid"].as_str().unwrap_or_default(),
+            "event_type": event["type"].as_str().unwrap_or_default(),
+            "create_time": create_time(&value["ts"]),
+            "token": value["token"].as_str().unwrap_or_default(),
+            "app_id": event["app_id"].as_str().unwrap_or_default(),
+            "tenant_key": event["tenant_key"].as_str().unwrap_or_default(),
+        },
+        "event": event,
+    })
+}
+
+/// 1.0 版本的 ts 为秒级时间戳, 转换为毫秒
+fn create_time(ts: &Value) -> String {
+    let seconds = match ts {
+        Value::String(ts) => ts.parse::<f64>().ok(),
+        Value::Number(ts) => ts.as_f64(),
+        _ => None,
+    };
+    match seconds {
+        Some(seconds) => ((seconds * 1000.0) as u64).to_string(),
+        None => ts.as_str().unwrap_or_default().to_string(),
+    }
+}
+
+/// 应用商店应用的 app_ticket 事件 `app_ticket`
+///
+/// [app_ticket 事件](https://open.feishu.cn/document/server-docs/authentication-management/access-token/event/app-ticket-events)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppTicketEvent {
+    pub app_id: String,
+    pub app_ticket: String,
+}
+
+impl EventDispatcher {
+    /// app_ticket 事件, 每小时推送一次
+    pub fn on_app_ticket<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<AppTicketEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("app_ticket", handler)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::json;
+
+    use crate::event::{
+        v1::{normalize, EventSchema},
+        EventDispatcher,
+    };
+
+    const APP_TICKET: &str = r#"{
+        "ts": "1502199207.7171419",
+        "uuid": "bc447199585340d1f3728d26b1c0297a",
+        "token": "41a9425ea7df4536a7623e38fa321bae",
+        "type": "event_callback",
+        "event": {
+            "app_id": "cli_9e28cb7ba56a100e",
+            "app_ticket": "xxxxx",
+            "type": "app_ticket"
+        }
+    }"#;
+
+    #[test]
+    fn test_normalize() {
+        let value = serde_json::from_str(APP_TICKET).unwrap();
+        assert_eq!(EventSchema::detect(&value), Some(EventSchema::V1));
+
+        let value = normalize(value);
+        assert_eq!(EventSchema::detect(&value), Some(EventSchema::V2));
+        assert_eq!(
+            value["header"],
+            json!({
+                "event_id": "bc447199585340d1f3728d26b1c0297a",
+                "event_type": "app_ticket",
+                "create_time": "1502199207717",
+                "token": "41a9425ea7df4536a7623e38fa321bae",
+                "app_id": "cli_9e28cb7ba56a100e",
+                "tenant_key": ""
+            })
+        );
+        assert_eq!(value["event"]["app_ticket"], "xxxxx");
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_v1_event() {
+        let received = Arc::new(Mutex::new(None));
+        let sink = Arc::clone(&received);
+        let dispatcher = EventDispatcher::new().on_app_ticket(move |event| {
+            let sink = Arc::clone(&sink);
+            async move {
+                assert_eq!(event.header.event_id, "bc447199585340d1f3728d26b1c0297a");
+                *sink.lock().unwrap() = Some(event.event.app_ticket);
+                Ok(())
+            }
+        });
+
+        dispatcher.dispatch(APP_TICKET.as_bytes()).await.unwrap();
+        assert_eq!(received.lock().unwrap().as_deref(), Some("xxxxx"));
+    }
+}
@@ -0,0 +1,495 @@
+//! Webhook 方式接收事件
+//!
+//! [`EventDispatcher::handle`] 不依赖具体的 HTTP 框架, 只需要把请求头和请求体转换为 [`EventRequest`],
+//! 再把 [`EventResponse`] 写回即可。
+//!
+//! [接收事件](https://open.feishu.cn/document/server-docs/event-subscription-guide/event-subscription-configure-/request-url-configuration-case)
+use std::{collections::HashMap, time::Duration};
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use cbc::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+use log::warn;
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    StatusCode,
+};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    core::{error::LarkAPIError, SDKResult},
+    event::{v1, EventDispatcher},
+};
+
+pub const HEADER_REQUEST_TIMESTAMP: &str = "X-Lark-Request-Timestamp";
+pub const HEADER_REQUEST_NONCE: &str = "X-Lark-Request-Nonce";
+pub const HEADER_SIGNATURE: &str = "X-Lark-Signature";
+
+/// 默认的响应时限, 开放平台要求 3 秒内应答
+pub const DEFAULT_RESPONSE_TIMEOUT: Duration = Duration::from_millis(2500);
+
+type Aes256CbcDec = cbc::Decryptor<aes::Aes256>;
+
+/// 事件回调请求
+#[derive(Debug, Clone, Default)]
+pub struct EventRequest {
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl EventRequest {
+    pub fn new(headers: HashMap<String, String>, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            headers,
+            body: body.into(),
+        }
+    }
+
+    /// 由 HTTP 框架的请求头和请求体构造, 忽略无法按字符串读取的请求头
+    pub fn from_headers(headers: &HeaderMap, body: impl Into<Vec<u8>>) -> Self {
+        let headers = headers
+            .iter()
+            .filter_map(|(k, v)| Some((k.to_string(), v.to_str().ok()?.to_string())))
+            .collect();
+        Self::new(headers, body)
+    }
+
+    /// 获取请求头, 忽略大小写
+    pub fn header(&self, key: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(key))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// 事件回调响应
+#[derive(Debug, Clone)]
+pub struct EventResponse {
+    pub status_code: u16,
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+impl EventResponse {
+    pub fn json(status_code: u16, body: &Value) -> Self {
+        Self {
+            status_code,
+            headers: HashMap::from([(
+                "Content-Type".to_string(),
+                "application/json; charset=utf-8".to_string(),
+            )]),
+            body: body.to_string().into_bytes(),
+        }
+    }
+
+    /// 响应状态码, 无效时按 200 处理
+    pub fn status(&self) -> StatusCode {
+        StatusCode::from_u16(self.status_code).unwrap_or(StatusCode::OK)
+    }
+
+    /// 转换为 HTTP 框架的响应头, 忽略无效的键值
+    pub fn header_map(&self) -> HeaderMap {
+        self.headers
+            .iter()
+            .filter_map(|(k, v)| {
+                Some((
+                    HeaderName::try_from(k).ok()?,
+                    HeaderValue::try_from(v).ok()?,
+                ))
+            })
+            .collect()
+    }
+}
+
+impl EventDispatcher {
+    /// 处理事件回调请求, 包括 URL 校验、签名校验、解密以及分发事件
+    ///
+    /// 请求体无法解析或解密时返回 400, 签名或 Verification Token 校验失败时返回 401,
+    /// 处理函数出错时返回 500
+    pub async fn handle(&self, req: EventRequest) -> EventResponse {
+        let payload = match self.decode_request(&req) {
+            Ok(payload) => payload,
+            Err(e) => return error_response(400, e),
+        };
+        if let Err(e) = self.verify_request(&req, &payload) {
+            return error_response(401, e);
+        }
+
+        match self.handle_payload(payload).await {
+            Ok(body) => EventResponse::json(200, &body),
+            Err(e) => error_response(500, e),
+        }
+    }
+
+    async fn handle_payload(&self, payload: Value) -> SDKResult<Value> {
+        if is_url_verification(&payload) {
+            return Ok(json!({ "challenge": payload["challenge"] }));
+        }
+
+        // 带响应体的处理函数需要等待其返回, 否则响应体会丢失
+        let payload = v1::normalize(payload);
+        if self.has_response(payload["header"]["event_type"].as_str().unwrap_or_default()) {
+            let resp = self.dispatch_value(payload).await?;
+            return Ok(resp.unwrap_or_else(|| json!({ "msg": "success" })));
+        }
+
+        // 处理函数在后台执行, 超过响应时限时先应答, 避免平台重推
+        let dispatcher = self.clone();
+        let task = tokio::spawn(async move { dispatcher.dispatch_value(payload).await });
+        let resp = match tokio::time::timeout(self.response_timeout, task).await {
+            Ok(Ok(resp)) => resp?,
+            Ok(Err(e)) => return Err(LarkAPIError::EventError(e.to_string())),
+            Err(_) => {
+                warn!(
+                    "event handler exceeded {:?}, continue in background",
+                    self.response_timeout
+                );
+                None
+            }
+        };
+        Ok(resp.unwrap_or_else(|| json!({ "msg": "success" })))
+    }
+
+    /// 校验请求并返回解密后的事件
+    pub fn parse_request(&self, req: &EventRequest) -> SDKResult<Value> {
+        let payload = self.decode_request(req)?;
+        self.verify_request(req, &payload)?;
+
+        Ok(payload)
+    }
+
+    /// 解析请求体, 加密的事件先解密
+    fn decode_request(&self, req: &EventRequest) -> SDKResult<Value> {
+        let payload = serde_json::from_slice::<Value>(&req.body)?;
+        let Some(encrypt) = payload["encrypt"].as_str() else {
+            return Ok(payload);
+        };
+        let encrypt_key = self
+            .encrypt_key
+            .as_ref()
+            .ok_or_else(|| LarkAPIError::EventError("encrypt key is not set".to_string()))?;
+
+        let plaintext = decrypt(encrypt, encrypt_key.expose())?;
+
+        Ok(serde_json::from_str(&plaintext)?)
+    }
+
+    /// 校验签名与 Verification Token, URL 校验请求不带签名
+    fn verify_request(&self, req: &EventRequest, payload: &Value) -> SDKResult<()> {
+        if !is_url_verification(payload) {
+            self.verify_signature(req)?;
+        }
+        self.verify_token(payload)
+    }
+
+    /// 设置了 Encrypt Key 时校验 `X-Lark-Signature`
+    fn verify_signature(&self, req: &EventRequest) -> SDKResult<()> {
+        let Some(encrypt_key) = self.encrypt_key.as_ref() else {
+            return Ok(());
+        };
+
+        let expected = signature(
+            req.header(HEADER_REQUEST_TIMESTAMP).unwrap_or_default(),
+            req.header(HEADER_REQUEST_NONCE).unwrap_or_default(),
+            encrypt_key.expose(),
+            &req.body,
+        );
+        let actual = req.header(HEADER_SIGNATURE).unwrap_or_default();
+        if !constant_time_eq(actual.as_bytes(), expected.as_bytes()) {
+            return Err(LarkAPIError::EventError(
+                "signature verification failed".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// 设置了 Verification Token 时校验事件中的 token
+    fn verify_token(&self, payload: &Value) -> SDKResult<()> {
+        let Some(verification_token) = self.verification_token.as_ref() else {
+            return Ok(());
+        };
+
+        // 2.0 版本的 token 在 header 中
+        let token = payload["header"]["token"]
+            .as_str()
+            .or_else(|| payload["token"].as_str())
+            .unwrap_or_default();
+        if !constant_time_eq(token.as_bytes(), verification_token.expose().as_bytes()) {
+            return Err(LarkAPIError::EventError(
+                "verification token mismatch".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn is_url_verification(payload: &Value) -> bool {
+    payload["type"].as_str() == Some("url_verification")
+}
+
+fn error_response(status_code: u16, e: LarkAPIError) -> EventResponse {
+    warn!("handle event request error: {e}");
+    EventResponse::json(status_code, &json!({ "msg": e.to_string() }))
+}
+
+/// 比较签名或 token, 耗时只与长度有关, 避免通过响应时间逐字节猜测
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// 计算事件签名, sha256(timestamp + nonce + encrypt_key + body)
+pub fn signature(timestamp: &str, nonce: &str, encrypt_key: &str, body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(timestamp.as_bytes());
+    hasher.update(nonce.as_bytes());
+    hasher.update(encrypt_key.as_bytes());
+    hasher.update(body);
+    format!("{:x}", hasher.finalize())
+}
+
+/// 解密事件, 使用 AES-256-CBC, 密钥为 Encrypt Key 的 SHA-256, 密文前 16 字节为 IV
+pub fn decrypt(encrypt: &str, encrypt_key: &str) -> SDKResult<String> {
+    let key = Sha256::digest(encrypt_key.as_bytes());
+    let data = BASE64_STANDARD
+        .decode(encrypt)
+        .map_err(|e| LarkAPIError::EventError(format!("invalid encrypt data: {e}")))?;
+    if data.len() < 16 {
+        return Err(LarkAPIError::EventError(
+            "encrypt data is too short".to_string(),
+        ));
+    }
+
+    let (iv, ciphertext) = data.split_at(16);
+    let plaintext = Aes256CbcDec::new_from_slices(&key, iv)
+        .map_err(|e| LarkAPIError::EventError(e.to_string()))?
+        .decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+        .map_err(|e| LarkAPIError::EventError(format!("decrypt error: {e}")))?;
+
+    String::from_utf8(plaintext).map_err(|e| LarkAPIError::EventError(e.to_string()))
+}
+
+#[cfg(test)]
+pub(crate) mod test {
+    use std::{
+        collections::HashMap,
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, Mutex,
+        },
+        time::Duration,
+    };
+
+    use serde_json::{json, Value};
+
+    use crate::event::{
+        webhook::{
+            decrypt, signature, EventRequest, HEADER_REQUEST_NONCE, HEADER_REQUEST_TIMESTAMP,
+            HEADER_SIGNATURE,
+        },
+        EventDispatcher, EventV2,
+    };
+
+    pub(crate) const ENCRYPT_KEY: &str = "test key";
+
+    /// 加密的 im.chat.disbanded_v1 事件, 由 `openssl enc -aes-256-cbc` 以随机 IV 加密,
+    /// 密钥为 sha256("test key")
+    pub(crate) const ENCRYPTED_EVENT: &str = "SUTjscUyar3LfzENMRE9HTw6H5sXLc9bn2nhnjS/qMMbTtyrOU7eKq2Ko/GyjYZxvQeZqW2PaJxnurruDUY5p6vAp7Unz3UD2zVmf9c3/rJlkTyFG6hnzQkz3ODsVS18rJV/XMrU2duZ7MivRSDXjpi19TwC6TRwRI+WQyVBcfazEaGUztLB3hJ4o/GjP6bQcqVrbaT74jtDJWqylBlzKMcn1Dno91+vKSc/E6XumuRkhDwxlxq+MI5W1Vw1dRKxJaaHpQjBTX3CgLmvf8s+7YB/50395GYxsp5A3YOM4M0pvWEkBZECBt9pu3UqJYVKVLVVXoS1CmSIic0LLin5R+3laKRLayMLkz+rQ/OqXBTODQzD5rV3E7k4ssxAxRfJrfxIKdlkRcX/E1qiyMr1oQ==";
+
+    /// `ENCRYPTED_EVENT` 请求体的签名, 由 `sha256sum` 计算
+    /// sha256("1608725989" + "5f3c7d9e2a" + "test key" + body)
+    pub(crate) const EVENT_SIGNATURE: &str =
+        "880bd320295be250aaf47665b3aaa09f8a3857c42cc666f149c1a4d34b9a0f2d";
+
+    pub(crate) const EVENT_TIMESTAMP: &str = "1608725989";
+    pub(crate) const EVENT_NONCE: &str = "5f3c7d9e2a";
+
+    fn signed_request(body: String, signature: &str) -> EventRequest {
+        let headers = HashMap::from([
+            (
+                HEADER_REQUEST_TIMESTAMP.to_string(),
+                EVENT_TIMESTAMP.to_string(),
+            ),
+            (HEADER_REQUEST_NONCE.to_string(), EVENT_NONCE.to_string()),
+            (HEADER_SIGNATURE.to_string(), signature.to_string()),
+        ]);
+        EventRequest::new(headers, body)
+    }
+
+    #[test]
+    fn test_decrypt() {
+        assert_eq!(
+            decrypt("P37w+VZImNgPEO1RBhJ6RtKl7n6zymIbEG1pReEzghk=", ENCRYPT_KEY).unwrap(),
+            "hello world"
+        );
+        assert!(decrypt("P37w+VZImNgPEO1RBhJ6RtKl7n6zymIbEG1pReEzghk=", "wrong key").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_slow_handler() {
+        let finished = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&finished);
+        let dispatcher = EventDispatcher::new()
+            .with_response_timeout(Duration::from_millis(10))
+            .register("im.chat.disbanded_v1", move |_: EventV2<Value>| {
+                let flag = Arc::clone(&flag);
+                async move {
+                    tokio::time::sleep(Duration::from_millis(50)).await;
+                    flag.store(true, Ordering::SeqCst);
+                    Ok(())
+                }
+            });
+        let body = r#"{"schema":"2.0","header":{"event_type":"im.chat.disbanded_v1"},"event":{}}"#;
+
+        let resp = dispatcher
+            .handle(EventRequest::new(HashMap::new(), body))
+            .await;
+        assert_eq!(resp.status_code, 200);
+        assert!(!finished.load(Ordering::SeqCst));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(finished.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_slow_handler_with_response() {
+        let dispatcher = EventDispatcher::new()
+            .with_response_timeout(Duration::from_millis(10))
+            .register_with_response("card.action.trigger", |_: EventV2<Value>| async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(json!({"toast": {"type": "success", "content": "已处理"}}))
+            });
+        let body = r#"{"schema":"2.0","header":{"event_type":"card.action.trigger"},"event":{}}"#;
+
+        let resp = dispatcher
+            .handle(EventRequest::new(HashMap::new(), body))
+            .await;
+        assert_eq!(resp.status_code, 200);
+        let body: Value = serde_json::from_slice(&resp.body).unwrap();
+        assert_eq!(
+            body,
+            json!({"toast": {"type": "success", "content": "已处理"}})
+        );
+    }
+
+    #[test]
+    fn test_signature() {
+        let body = json!({ "encrypt": ENCRYPTED_EVENT }).to_string();
+        assert_eq!(
+            signature("1608725989", "5f3c7d9e2a", ENCRYPT_KEY, body.as_bytes()),
+            EVENT_SIGNATURE
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signed_event() {
+        let chat_id = Arc::new(Mutex::new(String::new()));
+        let received = Arc::clone(&chat_id);
+        let dispatcher = EventDispatcher::new()
+            .with_encrypt_key(ENCRYPT_KEY)
+            .with_verification_token("xxxxxx")
+            .register("im.chat.disbanded_v1", move |event: EventV2<Value>| {
+                let received = Arc::clone(&received);
+                async move {
+                    *received.lock().unwrap() = event.event["chat_id"].as_str().unwrap().into();
+                    Ok(())
+                }
+            });
+        let body = json!({ "encrypt": ENCRYPTED_EVENT }).to_string();
+
+        let resp = dispatcher
+            .handle(signed_request(body.clone(), EVENT_SIGNATURE))
+            .await;
+        assert_eq!(resp.status_code, 200);
+        assert_eq!(
+            *chat_id.lock().unwrap(),
+            "oc_a0553eda9014c201e6969b478895c230"
+        );
+
+        // 签名错误
+        let mut tampered = EVENT_SIGNATURE.to_string();
+        tampered.replace_range(..1, "0");
+        let resp = dispatcher.handle(signed_request(body, &tampered)).await;
+        assert_eq!(resp.status_code, 401);
+
+        // 请求体无法解析
+        let resp = dispatcher
+            .handle(signed_request("not json".to_string(), EVENT_SIGNATURE))
+            .await;
+        assert_eq!(resp.status_code, 400);
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_url_verification() {
+        let dispatcher = EventDispatcher::new()
+            .with_encrypt_key(ENCRYPT_KEY)
+            .with_verification_token("xxxxxx");
+        // 由 `openssl enc -aes-256-cbc` 以随机 IV 加密
+        let body = json!({
+            "encrypt": "SUTjscUyar3LfzENMRE9HbIXxQKDGBnauJOAPmJ9WvLiC/M7dxv3qFaQD/Q429msdpy3PNTfYu2aeRNwr5Qlo5eRPLo4wsAn+6GMkY7SmrswGWvix4QdhXsUZzkrdq5H"
+        });
+        let resp = dispatcher
+            .handle(EventRequest::new(HashMap::new(), body.to_string()))
+            .await;
+
+        assert_eq!(resp.status_code, 200);
+        let body: Value = serde_json::from_slice(&resp.body).unwrap();
+        assert_eq!(body, json!({"challenge": "ajls384kdjx98XX"}));
+    }
+
+    #[tokio::test]
+    async fn test_verify_token() {
+        let dispatcher = EventDispatcher::new()
+            .with_verification_token("token")
+            .register("im.chat.disbanded_v1", |_: EventV2<Value>| async { Ok(()) });
+        let event = |token: &str| {
+            json!({
+                "schema": "2.0",
+                "header": {
+                    "event_id": "5e3702a84e847582be8db7fb73283c02",
+                    "event_type": "im.chat.disbanded_v1",
+                    "create_time": "1608725989000",
+                    "token": token,
+                    "app_id": "cli_9f5343c580712544",
+                    "tenant_key": "2ca1d211f64f6438"
+                },
+                "event": {}
+            })
+            .to_string()
+        };
+
+        let resp = dispatcher
+            .handle(EventRequest::new(HashMap::new(), event("token")))
+            .await;
+        assert_eq!(resp.status_code, 200);
+
+        let resp = dispatcher
+            .handle(EventRequest::new(HashMap::new(), event("other")))
+            .await;
+        assert_eq!(resp.status_code, 401);
+    }
+
+    #[tokio::test]
+    async fn test_verify_signature() {
+        let dispatcher = EventDispatcher::new().with_encrypt_key(ENCRYPT_KEY);
+        let body = r#"{"schema":"2.0","header":{"event_type":"im.chat.disbanded_v1"},"event":{}}"#;
+        let headers = HashMap::from([
+            (
+                "x-lark-request-timestamp".to_string(),
+                "1604908339".to_string(),
+            ),
+            ("x-lark-request-nonce".to_string(), "nonce123".to_string()),
+            ("x-lark-signature".to_string(), "invalid".to_string()),
+        ]);
+
+        let err = dispatcher
+            .parse_request(&EventRequest::new(headers, body))
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Event error: signature verification failed"
+        );
+    }
+}
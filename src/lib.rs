@@ -4,5 +4,6 @@ pub mod client;
 pub mod core;
 // pub mod message;
 pub mod custom_bot;
+pub mod event;
 pub mod prelude;
 pub mod service;
@@ -14,7 +14,7 @@ use crate::{
         req_option::RequestOption,
         SDKResult,
     },
-    service::bitable::v1::Person,
+    service::{bitable::v1::Person, common::UserIdType},
 };
 
 pub struct AppTableRecordService {
@@ -200,11 +200,11 @@ impl AppTableRecordSearchRequestBuilder {
     /// - user_id：标识一个用户在某个租户内的身份。同一个用户在租户 A 和租户 B 内的 User ID
     ///   是不同的。在同一个租户内，一个用户的 User ID 在所有应用（包括商店应用）中都保持一致。User
     ///   ID 主要用于在不同的应用间打通用户数据。
-    pub fn user_id_type(mut self, user_id_type: impl ToString) -> Self {
+    pub fn user_id_type(mut self, user_id_type: impl Into<UserIdType>) -> Self {
         self.request
             .api_request
             .query_params
-            .insert("user_id_type".to_string(), user_id_type.to_string());
+            .insert("user_id_type".to_string(), user_id_type.into().to_string());
         self
     }
 
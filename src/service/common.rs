@@ -0,0 +1,530 @@
+//! 各服务通用的参数类型
+//!
+//! 所有枚举均可通过字符串构造, 未知取值会落入 `Custom` 变体, 以兼容开放平台后续新增的取值。
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// 定义字符串枚举, 额外生成 `Custom(String)` 变体作为未知取值的兜底,
+/// 并实现 `Display`、`From<&str>`、`From<String>` 以及按字符串序列化
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $value:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )*
+            /// 自定义取值
+            Custom(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Custom(value) => value.as_str(),
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    _ => $name::Custom(value.to_string()),
+                }
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                value.as_str().into()
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok(String::deserialize(deserializer)?.into())
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// 消息接收者 ID 类型
+    pub enum ReceiveIdType {
+        /// 标识一个用户在某个应用中的身份
+        OpenId => "open_id",
+        /// 标识一个用户在某个应用开发商下的身份
+        UnionId => "union_id",
+        /// 标识一个用户在某个租户内的身份
+        UserId => "user_id",
+        /// 以用户的真实邮箱来标识用户
+        Email => "email",
+        /// 以群 ID 来标识群聊
+        ChatId => "chat_id",
+    }
+}
+
+string_enum! {
+    /// 用户 ID 类型
+    pub enum UserIdType {
+        /// 标识一个用户在某个应用中的身份。同一个用户在不同应用中的 Open ID 不同
+        OpenId => "open_id",
+        /// 标识一个用户在某个应用开发商下的身份。同一用户在同一开发商下的应用中的 Union ID 是相同的
+        UnionId => "union_id",
+        /// 标识一个用户在某个租户内的身份。在同一个租户内，一个用户的 User ID 在所有应用中都保持一致
+        UserId => "user_id",
+    }
+}
+
+string_enum! {
+    /// 排序方向
+    pub enum SortDirection {
+        /// 升序
+        Asc => "ASC",
+        /// 降序
+        Desc => "DESC",
+    }
+}
+
+string_enum! {
+    /// 消息类型
+    pub enum MsgType {
+        /// 文本
+        Text => "text",
+        /// 富文本
+        Post => "post",
+        /// 图片
+        Image => "image",
+        /// 文件
+        File => "file",
+        /// 语音
+        Audio => "audio",
+        /// 视频
+        Media => "media",
+        /// 表情包
+        Sticker => "sticker",
+        /// 卡片
+        Interactive => "interactive",
+        /// 分享群名片
+        ShareChat => "share_chat",
+        /// 分享个人名片
+        ShareUser => "share_user",
+    }
+}
+
+string_enum! {
+    /// 历史消息排序方式
+    pub enum MessageSortType {
+        /// 按消息创建时间升序排列
+        ByCreateTimeAsc => "ByCreateTimeAsc",
+        /// 按消息创建时间降序排列
+        ByCreateTimeDesc => "ByCreateTimeDesc",
+    }
+}
+
+string_enum! {
+    /// 群组排序方式
+    pub enum ChatSortType {
+        /// 按群组创建时间升序排列
+        ByCreateTimeAsc => "ByCreateTimeAsc",
+        /// 按群组活跃时间降序排列
+        ByActiveTimeDesc => "ByActiveTimeDesc",
+    }
+}
+
+string_enum! {
+    /// 文件排序规则
+    pub enum FileOrderBy {
+        /// 编辑时间排序
+        EditedTime => "EditedTime",
+        /// 创建时间排序
+        CreatedTime => "CreatedTime",
+    }
+}
+
+string_enum! {
+    /// 云文档类型
+    pub enum DocType {
+        /// 旧版文档
+        Doc => "doc",
+        /// 电子表格
+        Sheet => "sheet",
+        /// 云空间文件
+        File => "file",
+        /// 知识库节点
+        Wiki => "wiki",
+        /// 多维表格
+        Bitable => "bitable",
+        /// 新版文档
+        Docx => "docx",
+        /// 思维笔记
+        Mindnote => "mindnote",
+        /// 妙记
+        Minutes => "minutes",
+        /// 幻灯片
+        Slides => "slides",
+    }
+}
+
+string_enum! {
+    /// 允许内容被分享到组织外
+    pub enum ExternalAccessEntity {
+        /// 打开
+        Open => "open",
+        /// 关闭
+        Closed => "closed",
+        /// 允许分享给关联组织
+        AllowSharePartnerTenant => "allow_share_partner_tenant",
+    }
+}
+
+string_enum! {
+    /// 谁可以创建副本、打印、下载, 以及谁可以复制内容
+    pub enum SecurityEntity {
+        /// 拥有可阅读权限的用户
+        AnyoneCanView => "anyone_can_view",
+        /// 拥有可编辑权限的用户
+        AnyoneCanEdit => "anyone_can_edit",
+        /// 拥有可管理权限（包括我）的用户
+        OnlyFullAccess => "only_full_access",
+    }
+}
+
+string_enum! {
+    /// 谁可以评论
+    pub enum CommentEntity {
+        /// 拥有可阅读权限的用户
+        AnyoneCanView => "anyone_can_view",
+        /// 拥有可编辑权限的用户
+        AnyoneCanEdit => "anyone_can_edit",
+    }
+}
+
+string_enum! {
+    /// 谁可以添加和管理协作者-组织维度
+    pub enum ShareEntity {
+        /// 所有可阅读或编辑此文档的用户
+        Anyone => "anyone",
+        /// 组织内所有可阅读或编辑此文档的用户
+        SameTenant => "same_tenant",
+    }
+}
+
+string_enum! {
+    /// 谁可以添加和管理协作者-协作者维度
+    pub enum ManageCollaboratorEntity {
+        /// 拥有可阅读权限的协作者
+        CollaboratorCanView => "collaborator_can_view",
+        /// 拥有可编辑权限的协作者
+        CollaboratorCanEdit => "collaborator_can_edit",
+        /// 拥有可管理权限（包括我）的协作者
+        CollaboratorFullAccess => "collaborator_full_access",
+    }
+}
+
+string_enum! {
+    /// 链接分享设置
+    pub enum LinkShareEntity {
+        /// 组织内获得链接的人可阅读
+        TenantReadable => "tenant_readable",
+        /// 组织内获得链接的人可编辑
+        TenantEditable => "tenant_editable",
+        /// 关联组织的人可阅读
+        PartnerTenantReadable => "partner_tenant_readable",
+        /// 关联组织的人可编辑
+        PartnerTenantEditable => "partner_tenant_editable",
+        /// 互联网上获得链接的任何人可阅读
+        AnyoneReadable => "anyone_readable",
+        /// 互联网上获得链接的任何人可编辑
+        AnyoneEditable => "anyone_editable",
+        /// 关闭链接分享
+        Closed => "closed",
+    }
+}
+
+string_enum! {
+    /// 表情类型, 即表情文案说明中的 emoji_type
+    ///
+    /// [表情文案说明](https://open.feishu.cn/document/server-docs/im-v1/message-reaction/emojis-introduce)
+    pub enum EmojiType {
+        /// OK
+        Ok => "OK",
+        /// 赞
+        ThumbsUp => "THUMBSUP",
+        /// 谢谢
+        Thanks => "THANKS",
+        /// 加油
+        Muscle => "MUSCLE",
+        /// 比心
+        FingerHeart => "FINGERHEART",
+        /// 鼓掌
+        Applause => "APPLAUSE",
+        /// 碰拳
+        FistBump => "FISTBUMP",
+        /// +1
+        JiaYi => "JIAYI",
+        /// 完成
+        Done => "DONE",
+        /// 微笑
+        Smile => "SMILE",
+        /// 脸红
+        Blush => "BLUSH",
+        /// 大笑
+        Laugh => "LAUGH",
+        /// 坏笑
+        Smirk => "SMIRK",
+        /// 笑哭
+        Lol => "LOL",
+        /// 捂脸
+        Facepalm => "FACEPALM",
+        /// 爱慕
+        Love => "LOVE",
+        /// 眨眼
+        Wink => "WINK",
+        /// 得意
+        Proud => "PROUD",
+        /// 机智
+        Witty => "WITTY",
+        /// 聪明
+        Smart => "SMART",
+        /// 皱眉
+        Scowl => "SCOWL",
+        /// 思考
+        Thinking => "THINKING",
+        /// 抽泣
+        Sob => "SOB",
+        /// 流泪
+        Cry => "CRY",
+        /// 出错
+        Error => "ERROR",
+        /// 抠鼻
+        NosePick => "NOSEPICK",
+        /// 傲慢
+        Haughty => "HAUGHTY",
+        /// 打脸
+        Slap => "SLAP",
+        /// 吐血
+        SpitBlood => "SPITBLOOD",
+        /// 焦虑
+        Toasted => "TOASTED",
+        /// 斜眼
+        Glance => "GLANCE",
+        /// 呆
+        Dull => "DULL",
+        /// 天真
+        InnocentSmile => "INNOCENTSMILE",
+        /// 愉快
+        Joyful => "JOYFUL",
+        /// 哇
+        Wow => "WOW",
+        /// 鬼脸
+        Trick => "TRICK",
+        /// 耶
+        Yeah => "YEAH",
+        /// 够了
+        Enough => "ENOUGH",
+        /// 泪奔
+        Tears => "TEARS",
+        /// 尴尬
+        Embarrassed => "EMBARRASSED",
+        /// 飞吻
+        Kiss => "KISS",
+        /// 亲亲
+        Smooch => "SMOOCH",
+        /// 流口水
+        Drool => "DROOL",
+        /// 着迷
+        Obsessed => "OBSESSED",
+        /// 发财
+        Money => "MONEY",
+        /// 调皮
+        Tease => "TEASE",
+        /// 炫耀
+        ShowOff => "SHOWOFF",
+        /// 安慰
+        Comfort => "COMFORT",
+        /// 拍手
+        Clap => "CLAP",
+        /// 表扬
+        Praise => "PRAISE",
+        /// 奋斗
+        Strive => "STRIVE",
+        /// 闭嘴
+        Silent => "SILENT",
+        /// 再见
+        Wave => "WAVE",
+        /// 疑问
+        What => "WHAT",
+        /// 不开心
+        Frown => "FROWN",
+        /// 害羞
+        Shy => "SHY",
+        /// 晕
+        Dizzy => "DIZZY",
+        /// 鄙视
+        LookDown => "LOOKDOWN",
+        /// 偷笑
+        Chuckle => "CHUCKLE",
+        /// 大哭
+        Wail => "WAIL",
+        /// 抓狂
+        Crazy => "CRAZY",
+        /// 委屈巴巴
+        Whimper => "WHIMPER",
+        /// 拥抱
+        Hug => "HUG",
+        /// 委屈
+        Wronged => "WRONGED",
+        /// 二哈
+        Husky => "HUSKY",
+        /// 嘘
+        Shhh => "SHHH",
+        /// 得意洋洋
+        Smug => "SMUG",
+        /// 生气
+        Angry => "ANGRY",
+        /// 敲打
+        Hammer => "HAMMER",
+        /// 震惊
+        Shocked => "SHOCKED",
+        /// 惊恐
+        Terror => "TERROR",
+        /// 骷髅
+        Skull => "SKULL",
+        /// 汗
+        Sweat => "SWEAT",
+        /// 无语
+        Speechless => "SPEECHLESS",
+        /// 睡觉
+        Sleep => "SLEEP",
+        /// 困
+        Drowsy => "DROWSY",
+        /// 打哈欠
+        Yawn => "YAWN",
+        /// 生病
+        Sick => "SICK",
+        /// 吐
+        Puke => "PUKE",
+        /// 敬礼
+        Salute => "SALUTE",
+        /// 握手
+        Shake => "SHAKE",
+        /// 击掌
+        HighFive => "HIGHFIVE",
+        /// 踩
+        ThumbsDown => "ThumbsDown",
+        /// Get
+        Get => "Get",
+        /// LGTM
+        Lgtm => "LGTM",
+        /// 在做了
+        OnIt => "OnIt",
+        /// 稍等
+        OneSecond => "OneSecond",
+        /// 打字
+        Typing => "Typing",
+        /// 叹气
+        Sigh => "Sigh",
+        /// 玫瑰
+        Rose => "ROSE",
+        /// 爱心
+        Heart => "HEART",
+        /// 心碎
+        HeartBroken => "HEARTBROKEN",
+        /// 庆祝
+        Party => "PARTY",
+        /// 啤酒
+        Beer => "BEER",
+        /// 蛋糕
+        Cake => "CAKE",
+        /// 礼物
+        Gift => "GIFT",
+        /// 咖啡
+        Coffee => "Coffee",
+        /// 是
+        Yes => "Yes",
+        /// 否
+        No => "No",
+        /// 对勾
+        CheckMark => "CheckMark",
+        /// 叉号
+        CrossMark => "CrossMark",
+        /// -1
+        MinusOne => "MinusOne",
+        /// 100 分
+        Hundred => "Hundred",
+        /// 图钉
+        Pin => "Pin",
+        /// 警报
+        Alarm => "Alarm",
+        /// 喇叭
+        Loudspeaker => "Loudspeaker",
+        /// 奖杯
+        Trophy => "Trophy",
+        /// 火
+        Fire => "Fire",
+        /// 炸弹
+        Bomb => "BOMB",
+        /// 音乐
+        Music => "Music",
+        /// 烟花
+        Fireworks => "FIREWORKS",
+        /// 红包
+        RedPacket => "REDPACKET",
+        /// 便便
+        Poop => "POOP",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::json;
+
+    use crate::service::common::{LinkShareEntity, ReceiveIdType, SortDirection, UserIdType};
+
+    #[test]
+    fn test_string_enum() {
+        assert_eq!(ReceiveIdType::ChatId.to_string(), "chat_id");
+        assert_eq!(SortDirection::from("DESC"), SortDirection::Desc);
+        assert_eq!(
+            UserIdType::from("new_id_type"),
+            UserIdType::Custom("new_id_type".to_string())
+        );
+        assert_eq!(
+            UserIdType::Custom("new_id_type".to_string()).to_string(),
+            "new_id_type"
+        );
+    }
+
+    #[test]
+    fn test_string_enum_serde() {
+        assert_eq!(
+            json!(LinkShareEntity::TenantReadable),
+            json!("tenant_readable")
+        );
+        let entity: LinkShareEntity = serde_json::from_value(json!("anyone_editable")).unwrap();
+        assert_eq!(entity, LinkShareEntity::AnyoneEditable);
+    }
+}
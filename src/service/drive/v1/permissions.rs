@@ -1,14 +1,20 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::core::{
-    api_req::ApiRequest,
-    api_resp::{ApiResponseTrait, BaseResponse},
-    config::Config,
-    constants::AccessTokenType,
-    http::Transport,
-    req_option::RequestOption,
-    SDKResult,
+use crate::{
+    core::{
+        api_req::ApiRequest,
+        api_resp::{ApiResponseTrait, BaseResponse},
+        config::Config,
+        constants::AccessTokenType,
+        http::Transport,
+        req_option::RequestOption,
+        SDKResult,
+    },
+    service::common::{
+        CommentEntity, DocType, ExternalAccessEntity, LinkShareEntity, ManageCollaboratorEntity,
+        SecurityEntity, ShareEntity,
+    },
 };
 
 pub struct PermissionsService {
@@ -74,7 +80,7 @@ pub struct GetPermissionRequest {
     /// - mindnote：思维笔记
     /// - minutes：妙记
     /// - slides：幻灯片
-    r#type: String,
+    r#type: Option<DocType>,
 }
 
 impl GetPermissionRequest {
@@ -110,12 +116,13 @@ impl GetPermissionRequestBuilder {
     /// - mindnote：思维笔记
     /// - minutes：妙记
     /// - slides：幻灯片
-    pub fn r#type(mut self, r#type: impl ToString) -> Self {
-        self.request.r#type = r#type.to_string();
+    pub fn r#type(mut self, r#type: impl Into<DocType>) -> Self {
+        let r#type = r#type.into();
         self.request
             .api_request
             .query_params
             .insert("type".to_string(), r#type.to_string());
+        self.request.r#type = Some(r#type);
         self
     }
 
@@ -147,7 +154,7 @@ pub struct PermissionPublic {
     /// - open：打开
     /// - closed：关闭
     /// - allow_share_partner_tenant：允许分享给关联组织
-    pub external_access_entity: Option<String>,
+    pub external_access_entity: Option<ExternalAccessEntity>,
     /// 谁可以创建副本、打印、下载
     ///
     /// 可选值有：
@@ -155,21 +162,21 @@ pub struct PermissionPublic {
     /// - anyone_can_view：拥有可阅读权限的用户
     /// - anyone_can_edit：拥有可编辑权限的用户
     /// - only_full_access：拥有可管理权限（包括我）的用户
-    pub security_entity:  Option<String>,
+    pub security_entity: Option<SecurityEntity>,
     /// 谁可以评论
     ///
     /// 可选值有：
     ///
     /// - anyone_can_view：拥有可阅读权限的用户
     /// - anyone_can_edit：拥有可编辑权限的用户
-    pub comment_entity: Option<String>,
+    pub comment_entity: Option<CommentEntity>,
     /// 谁可以添加和管理协作者-组织维度
     ///
     /// 可选值有：
     ///
     /// - anyone：所有可阅读或编辑此文档的用户
     /// - same_tenant：组织内所有可阅读或编辑此文档的用户
-    pub share_entity: Option<String>,
+    pub share_entity: Option<ShareEntity>,
     /// 谁可以添加和管理协作者-协作者维度
     ///
     /// 可选值有：
@@ -177,7 +184,7 @@ pub struct PermissionPublic {
     /// - collaborator_can_view：拥有可阅读权限的协作者
     /// - collaborator_can_edit：拥有可编辑权限的协作者
     /// - collaborator_full_access：拥有可管理权限（包括我）的协作者
-    pub manage_collaborator_entity: Option<String>,
+    pub manage_collaborator_entity: Option<ManageCollaboratorEntity>,
     /// 链接分享设置
     ///
     /// 可选值有：
@@ -189,7 +196,7 @@ pub struct PermissionPublic {
     /// - anyone_readable：互联网上获得链接的任何人可阅读（仅external_access=“open”时有效）
     /// - anyone_editable：互联网上获得链接的任何人可编辑（仅external_access=“open”时有效）
     /// - closed：关闭链接分享
-    pub link_share_entity: Option<String>,
+    pub link_share_entity: Option<LinkShareEntity>,
     /// 谁可以复制内容
     ///
     /// 可选值有：
@@ -197,7 +204,7 @@ pub struct PermissionPublic {
     /// - anyone_can_view：拥有可阅读权限的用户
     /// - anyone_can_edit：拥有可编辑权限的用户
     /// - only_full_access：拥有可管理权限（包括我）的协作者
-    pub copy_entity: Option<String>,
+    pub copy_entity: Option<SecurityEntity>,
     /// 节点是否已加锁，加锁之后不再继承父级页面的权限
     pub lock_switch: Option<bool>,
 }
@@ -218,9 +225,9 @@ pub struct PatchPermissionRequest {
     /// - open：打开
     /// - closed：关闭
     /// - allow_share_partner_tenant：允许分享给关联组织（只有租户后台设置仅允许关联组织分享，
-    /// 才能设置为该值）
+    ///   才能设置为该值）
     #[serde(skip_serializing_if = "Option::is_none")]
-    external_access_entity: Option<String>,
+    external_access_entity: Option<ExternalAccessEntity>,
     /// 谁可以创建副本、打印、下载
     ///
     /// 示例值："anyone_can_view"
@@ -231,7 +238,7 @@ pub struct PatchPermissionRequest {
     /// - anyone_can_edit：拥有可编辑权限的用户
     /// - only_full_access：拥有可管理权限（包括我）的用户
     #[serde(skip_serializing_if = "Option::is_none")]
-    security_entity: Option<String>,
+    security_entity: Option<SecurityEntity>,
     /// 谁可以评论
     ///
     /// 示例值："anyone_can_view"
@@ -241,7 +248,7 @@ pub struct PatchPermissionRequest {
     /// - anyone_can_view：拥有可阅读权限的用户
     /// - anyone_can_edit：拥有可编辑权限的用户
     #[serde(skip_serializing_if = "Option::is_none")]
-    comment_entity: Option<String>,
+    comment_entity: Option<CommentEntity>,
     /// 谁可以添加和管理协作者-组织维度
     ///
     /// 示例值："anyone"
@@ -251,7 +258,7 @@ pub struct PatchPermissionRequest {
     /// - anyone：所有可阅读或编辑此文档的用户
     /// - same_tenant：组织内所有可阅读或编辑此文档的用户
     #[serde(skip_serializing_if = "Option::is_none")]
-    share_entity: Option<String>,
+    share_entity: Option<ShareEntity>,
     /// 谁可以添加和管理协作者-协作者维度
     ///
     /// 示例值："collaborator_can_view"
@@ -262,7 +269,7 @@ pub struct PatchPermissionRequest {
     /// - collaborator_can_edit：拥有可编辑权限的协作者
     /// - collaborator_full_access：拥有可管理权限（包括我）的协作者
     #[serde(skip_serializing_if = "Option::is_none")]
-    manage_collaborator_entity: Option<String>,
+    manage_collaborator_entity: Option<ManageCollaboratorEntity>,
     /// 链接分享设置
     ///
     /// 示例值："tenant_readable"
@@ -278,9 +285,9 @@ pub struct PatchPermissionRequest {
     /// anyone_editable：互联网上获得链接的任何人可编辑（仅external_access_entity=“open”时有效）
     /// closed：关闭链接分享
     #[serde(skip_serializing_if = "Option::is_none")]
-    link_share_entity: Option<String>,
+    link_share_entity: Option<LinkShareEntity>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    copy_entity: Option<String>,
+    copy_entity: Option<SecurityEntity>,
 }
 
 impl PatchPermissionRequest {
@@ -316,11 +323,11 @@ impl PatchPermissionRequestBuilder {
     /// - mindnote：思维笔记
     /// - minutes：妙记
     /// - slides：幻灯片
-    pub fn r#type(mut self, r#type: impl ToString) -> Self {
+    pub fn r#type(mut self, r#type: impl Into<DocType>) -> Self {
         self.request
             .api_request
             .query_params
-            .insert("type".to_string(), r#type.to_string());
+            .insert("type".to_string(), r#type.into().to_string());
         self
     }
 
@@ -333,9 +340,12 @@ impl PatchPermissionRequestBuilder {
     /// - open：打开
     /// - closed：关闭
     /// - allow_share_partner_tenant：允许分享给关联组织（只有租户后台设置仅允许关联组织分享，
-    /// 才能设置为该值）
-    pub fn external_access_entity(mut self, external_access_entity: impl ToString) -> Self {
-        self.request.external_access_entity = Some(external_access_entity.to_string());
+    ///   才能设置为该值）
+    pub fn external_access_entity(
+        mut self,
+        external_access_entity: impl Into<ExternalAccessEntity>,
+    ) -> Self {
+        self.request.external_access_entity = Some(external_access_entity.into());
         self
     }
 
@@ -348,8 +358,8 @@ impl PatchPermissionRequestBuilder {
     /// - anyone_can_view：拥有可阅读权限的用户
     /// - anyone_can_edit：拥有可编辑权限的用户
     /// - only_full_access：拥有可管理权限（包括我）的用户
-    pub fn security_entity(mut self, security_entity: impl ToString) -> Self {
-        self.request.security_entity = Some(security_entity.to_string());
+    pub fn security_entity(mut self, security_entity: impl Into<SecurityEntity>) -> Self {
+        self.request.security_entity = Some(security_entity.into());
         self
     }
 
@@ -361,8 +371,8 @@ impl PatchPermissionRequestBuilder {
     ///
     /// - anyone_can_view：拥有可阅读权限的用户
     /// - anyone_can_edit：拥有可编辑权限的用户
-    pub fn comment_entity(mut self, comment_entity: impl ToString) -> Self {
-        self.request.comment_entity = Some(comment_entity.to_string());
+    pub fn comment_entity(mut self, comment_entity: impl Into<CommentEntity>) -> Self {
+        self.request.comment_entity = Some(comment_entity.into());
         self
     }
 
@@ -374,8 +384,8 @@ impl PatchPermissionRequestBuilder {
     ///
     /// - anyone：所有可阅读或编辑此文档的用户
     /// - same_tenant：组织内所有可阅读或编辑此文档的用户
-    pub fn share_entity(mut self, share_entity: impl ToString) -> Self {
-        self.request.share_entity = Some(share_entity.to_string());
+    pub fn share_entity(mut self, share_entity: impl Into<ShareEntity>) -> Self {
+        self.request.share_entity = Some(share_entity.into());
         self
     }
 
@@ -388,8 +398,11 @@ impl PatchPermissionRequestBuilder {
     /// - collaborator_can_view：拥有可阅读权限的协作者
     /// - collaborator_can_edit：拥有可编辑权限的协作者
     /// - collaborator_full_access：拥有可管理权限（包括我）的协作者
-    pub fn manage_collaborator_entity(mut self, manage_collaborator_entity: impl ToString) -> Self {
-        self.request.manage_collaborator_entity = Some(manage_collaborator_entity.to_string());
+    pub fn manage_collaborator_entity(
+        mut self,
+        manage_collaborator_entity: impl Into<ManageCollaboratorEntity>,
+    ) -> Self {
+        self.request.manage_collaborator_entity = Some(manage_collaborator_entity.into());
         self
     }
 
@@ -407,8 +420,8 @@ impl PatchPermissionRequestBuilder {
     /// anyone_readable：互联网上获得链接的任何人可阅读（仅external_access_entity=“open”时有效）
     /// anyone_editable：互联网上获得链接的任何人可编辑（仅external_access_entity=“open”时有效）
     /// closed：关闭链接分享
-    pub fn link_share_entity(mut self, link_share_entity: impl ToString) -> Self {
-        self.request.link_share_entity = Some(link_share_entity.to_string());
+    pub fn link_share_entity(mut self, link_share_entity: impl Into<LinkShareEntity>) -> Self {
+        self.request.link_share_entity = Some(link_share_entity.into());
         self
     }
 
@@ -419,8 +432,8 @@ impl PatchPermissionRequestBuilder {
     /// - anyone_can_view：拥有可阅读权限的用户
     /// - anyone_can_edit：拥有可编辑权限的用户
     /// - only_full_access：拥有可管理权限（包括我）的协作者
-    pub fn copy_entity(mut self, copy_entity: impl ToString) -> Self {
-        self.request.copy_entity = Some(copy_entity.to_string());
+    pub fn copy_entity(mut self, copy_entity: impl Into<SecurityEntity>) -> Self {
+        self.request.copy_entity = Some(copy_entity.into());
         self
     }
 
@@ -4,14 +4,17 @@ use log::error;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::core::{
-    api_req::ApiRequest,
-    api_resp::{ApiResponseTrait, BaseResponse, ResponseFormat},
-    config::Config,
-    constants::AccessTokenType,
-    http::Transport,
-    req_option::RequestOption,
-    SDKResult,
+use crate::{
+    core::{
+        api_req::ApiRequest,
+        api_resp::{ApiResponseTrait, BaseResponse, ResponseFormat},
+        config::Config,
+        constants::AccessTokenType,
+        http::Transport,
+        req_option::RequestOption,
+        SDKResult,
+    },
+    service::common::{FileOrderBy, SortDirection, UserIdType},
 };
 
 pub struct ExplorerService {
@@ -100,7 +103,7 @@ impl ExplorerService {
         &self,
         req: ListFolderRequest,
         option: Option<RequestOption>,
-    ) -> ListFolderIterator {
+    ) -> ListFolderIterator<'_> {
         ListFolderIterator {
             explorer_service: self,
             req,
@@ -312,12 +315,12 @@ impl ListFolderRequestBuilder {
     ///
     /// - EditedTime：编辑时间排序
     /// - CreatedTime：创建时间排序
-    /// 默认值：EditedTime
-    pub fn order_by(mut self, order_by: impl ToString) -> Self {
+    ///   默认值：EditedTime
+    pub fn order_by(mut self, order_by: impl Into<FileOrderBy>) -> Self {
         self.request
             .api_req
             .query_params
-            .insert("order_by".to_string(), order_by.to_string());
+            .insert("order_by".to_string(), order_by.into().to_string());
         self
     }
 
@@ -329,12 +332,12 @@ impl ListFolderRequestBuilder {
     ///
     /// - ASC：升序
     /// - DESC：降序
-    /// 默认值：DESC
-    pub fn direction(mut self, direction: impl ToString) -> Self {
+    ///   默认值：DESC
+    pub fn direction(mut self, direction: impl Into<SortDirection>) -> Self {
         self.request
             .api_req
             .query_params
-            .insert("direction".to_string(), direction.to_string());
+            .insert("direction".to_string(), direction.into().to_string());
         self
     }
 
@@ -352,12 +355,12 @@ impl ListFolderRequestBuilder {
     /// - user_id：标识一个用户在某个租户内的身份。同一个用户在租户 A 和租户 B 内的 User ID
     ///   是不同的。在同一个租户内，一个用户的 User ID 在所有应用（包括商店应用）中都保持一致。User
     ///   ID 主要用于在不同的应用间打通用户数据。了解更多：如何获取 User ID？
-    /// 默认值：open_id
-    pub fn user_id_type(mut self, user_id_type: impl ToString) -> Self {
+    ///   默认值：open_id
+    pub fn user_id_type(mut self, user_id_type: impl Into<UserIdType>) -> Self {
         self.request
             .api_req
             .query_params
-            .insert("user_id_type".to_string(), user_id_type.to_string());
+            .insert("user_id_type".to_string(), user_id_type.into().to_string());
         self
     }
 
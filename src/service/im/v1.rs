@@ -1,14 +1,21 @@
 use crate::{
     core::config::Config,
-    service::im::v1::{chats::ChatsService, message::MessageService},
+    service::im::v1::{
+        chats::ChatsService, message::MessageService, pin::PinService, reaction::ReactionService,
+    },
 };
 
 pub mod chats;
+pub mod event;
 pub mod message;
+pub mod pin;
+pub mod reaction;
 
 pub struct V1 {
     pub chats: ChatsService,
     pub message: MessageService,
+    pub pin: PinService,
+    pub reaction: ReactionService,
 }
 
 impl V1 {
@@ -17,7 +24,13 @@ impl V1 {
             chats: ChatsService {
                 config: config.clone(),
             },
-            message: MessageService { config },
+            message: MessageService {
+                config: config.clone(),
+            },
+            pin: PinService {
+                config: config.clone(),
+            },
+            reaction: ReactionService { config },
         }
     }
 }
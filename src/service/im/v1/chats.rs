@@ -1,16 +1,18 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
-use crate::core::{
-    api_req::ApiRequest,
-    api_resp::{ApiResponseTrait, ResponseFormat},
-    config::Config,
-    constants::AccessTokenType,
-    http::Transport,
-    req_option::RequestOption,
-    SDKResult,
+use crate::{
+    core::{
+        api_req::ApiRequest,
+        api_resp::{ApiResponseTrait, BaseResponse, ResponseFormat},
+        config::Config,
+        constants::AccessTokenType,
+        http::Transport,
+        req_option::RequestOption,
+        SDKResult,
+    },
+    service::common::{ChatSortType, UserIdType},
 };
-use crate::core::api_resp::BaseResponse;
 
 pub struct ChatsService {
     pub config: Config,
@@ -37,7 +39,7 @@ impl ChatsService {
         &self,
         list_chat_request: ListChatRequest,
         option: Option<RequestOption>,
-    ) -> ListChatIterator {
+    ) -> ListChatIterator<'_> {
         ListChatIterator {
             service: self,
             request: list_chat_request,
@@ -104,20 +106,20 @@ pub struct ListChatRequestBuilder {
 
 impl ListChatRequestBuilder {
     /// 用户 ID 类型
-    pub fn user_id_type(mut self, user_id_type: impl ToString) -> Self {
+    pub fn user_id_type(mut self, user_id_type: impl Into<UserIdType>) -> Self {
         self.request
             .api_req
             .query_params
-            .insert("user_id_type".to_string(), user_id_type.to_string());
+            .insert("user_id_type".to_string(), user_id_type.into().to_string());
         self
     }
 
     /// 群组排序方式
-    pub fn sort_type(mut self, sort_type: impl ToString) -> Self {
+    pub fn sort_type(mut self, sort_type: impl Into<ChatSortType>) -> Self {
         self.request
             .api_req
             .query_params
-            .insert("sort_type".to_string(), sort_type.to_string());
+            .insert("sort_type".to_string(), sort_type.into().to_string());
         self
     }
 
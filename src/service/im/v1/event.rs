@@ -0,0 +1,514 @@
+//! 消息与群组事件
+//!
+//! [事件列表](https://open.feishu.cn/document/server-docs/im-v1/message/events/receive)
+use std::future::Future;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::SDKResult,
+    event::{EventDispatcher, EventV2},
+};
+
+/// 用户 ID
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UserId {
+    pub union_id: Option<String>,
+    pub user_id: Option<String>,
+    pub open_id: Option<String>,
+}
+
+/// 多语言名称
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct I18nNames {
+    pub zh_cn: Option<String>,
+    pub en_us: Option<String>,
+    pub ja_jp: Option<String>,
+}
+
+/// 接收消息 `im.message.receive_v1`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReceiveEvent {
+    /// 事件的发送者
+    pub sender: EventSender,
+    /// 事件中包含的消息内容
+    pub message: EventMessage,
+}
+
+/// 事件的发送者
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventSender {
+    pub sender_id: UserId,
+    /// 消息发送者类型, 目前只支持用户 user 发送的消息
+    pub sender_type: String,
+    /// 为租户在飞书上的唯一标识
+    pub tenant_key: Option<String>,
+}
+
+/// 事件中包含的消息内容
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventMessage {
+    pub message_id: String,
+    /// 根消息id，用于回复消息场景
+    pub root_id: Option<String>,
+    /// 父消息的id，用于回复消息场景
+    pub parent_id: Option<String>,
+    /// 消息发送时间（毫秒）
+    pub create_time: String,
+    /// 消息更新时间（毫秒）
+    pub update_time: Option<String>,
+    /// 消息所在的群组 ID
+    pub chat_id: String,
+    /// 消息所属的话题 ID
+    pub thread_id: Option<String>,
+    /// 消息所在的群组类型, p2p 或 group
+    pub chat_type: String,
+    /// 消息类型
+    pub message_type: String,
+    /// 消息内容, json 格式
+    pub content: String,
+    /// 被提及用户的信息
+    pub mentions: Option<Vec<EventMention>>,
+    /// 用户代理数据
+    pub user_agent: Option<String>,
+}
+
+/// 被提及用户的信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventMention {
+    /// mention key, 如 `@_user_1`
+    pub key: String,
+    pub id: UserId,
+    /// 用户姓名
+    pub name: String,
+    pub tenant_key: Option<String>,
+}
+
+/// 消息已读 `im.message.message_read_v1`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReadEvent {
+    pub reader: MessageReader,
+    /// 已读消息的 ID 列表
+    pub message_id_list: Vec<String>,
+}
+
+/// 消息的阅读者
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReader {
+    pub reader_id: UserId,
+    /// 阅读时间（毫秒）
+    pub read_time: String,
+    pub tenant_key: Option<String>,
+}
+
+/// 新增或删除消息表情回复
+/// `im.message.reaction.created_v1` / `im.message.reaction.deleted_v1`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageReactionEvent {
+    pub message_id: String,
+    /// 表情回复的资源类型
+    pub reaction_type: ReactionType,
+    /// 操作人类型, user 或 app
+    pub operator_type: String,
+    /// 操作人为用户时的 ID
+    pub user_id: Option<UserId>,
+    /// 操作人为应用时的 App ID
+    pub app_id: Option<String>,
+    /// 操作时间（毫秒）
+    pub action_time: String,
+}
+
+/// 表情回复的资源类型
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionType {
+    /// emoji 类型
+    pub emoji_type: String,
+}
+
+/// 机器人进群或被移出群 `im.chat.member.bot.added_v1` / `im.chat.member.bot.deleted_v1`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMemberBotEvent {
+    pub chat_id: String,
+    /// 操作者的 ID
+    pub operator_id: UserId,
+    /// 是否是外部群
+    pub external: bool,
+    /// 操作者的租户 Key
+    pub operator_tenant_key: Option<String>,
+    /// 群名称
+    pub name: Option<String>,
+    /// 群国际化名称
+    pub i18n_names: Option<I18nNames>,
+}
+
+/// 用户进群、撤销拉用户进群或出群
+///
+/// `im.chat.member.user.added_v1` / `im.chat.member.user.withdrawn_v1` /
+/// `im.chat.member.user.deleted_v1`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMemberUserEvent {
+    pub chat_id: String,
+    /// 操作者的 ID
+    pub operator_id: UserId,
+    /// 是否是外部群
+    pub external: bool,
+    /// 操作者的租户 Key
+    pub operator_tenant_key: Option<String>,
+    /// 被添加、撤销或移除的用户列表
+    pub users: Vec<ChatMemberUser>,
+    /// 群名称
+    pub name: Option<String>,
+    /// 群国际化名称
+    pub i18n_names: Option<I18nNames>,
+}
+
+/// 群成员
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMemberUser {
+    /// 用户名字
+    pub name: Option<String>,
+    pub tenant_key: Option<String>,
+    pub user_id: UserId,
+}
+
+/// 群配置修改 `im.chat.updated_v1`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatUpdatedEvent {
+    pub chat_id: String,
+    /// 操作者的 ID
+    pub operator_id: UserId,
+    /// 是否是外部群
+    pub external: bool,
+    /// 操作者的租户 Key
+    pub operator_tenant_key: Option<String>,
+    /// 更新后的群信息
+    pub after_change: ChatChange,
+    /// 更新前的群信息
+    pub before_change: ChatChange,
+    /// 群管理员变更
+    pub moderator_list: Option<ModeratorList>,
+}
+
+/// 群信息, 只包含发生变更的字段
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChatChange {
+    /// 群头像
+    pub avatar: Option<String>,
+    /// 群名称
+    pub name: Option<String>,
+    /// 群描述
+    pub description: Option<String>,
+    /// 群国际化名称
+    pub i18n_names: Option<I18nNames>,
+    /// 加人入群权限
+    pub add_member_permission: Option<String>,
+    /// 群分享权限
+    pub share_card_permission: Option<String>,
+    /// at 所有人权限
+    pub at_all_permission: Option<String>,
+    /// 群编辑权限
+    pub edit_permission: Option<String>,
+    /// 加群审批
+    pub membership_approval: Option<String>,
+    /// 入群消息可见性
+    pub join_message_visibility: Option<String>,
+    /// 出群消息可见性
+    pub leave_message_visibility: Option<String>,
+    /// 发言权限
+    pub moderation_permission: Option<String>,
+    /// 群主 ID
+    pub owner_id: Option<UserId>,
+}
+
+/// 群管理员变更
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModeratorList {
+    /// 新增的管理员
+    pub added_member_list: Option<Vec<ChatMemberUser>>,
+    /// 删除的管理员
+    pub removed_member_list: Option<Vec<ChatMemberUser>>,
+}
+
+/// 群解散 `im.chat.disbanded_v1`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatDisbandedEvent {
+    pub chat_id: String,
+    /// 操作者的 ID
+    pub operator_id: UserId,
+    /// 是否是外部群
+    pub external: bool,
+    /// 操作者的租户 Key
+    pub operator_tenant_key: Option<String>,
+    /// 群名称
+    pub name: Option<String>,
+    /// 群国际化名称
+    pub i18n_names: Option<I18nNames>,
+}
+
+impl EventDispatcher {
+    /// 接收消息
+    pub fn on_message_receive_v1<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<MessageReceiveEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("im.message.receive_v1", handler)
+    }
+
+    /// 消息已读
+    pub fn on_message_read_v1<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<MessageReadEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("im.message.message_read_v1", handler)
+    }
+
+    /// 新增消息表情回复
+    pub fn on_message_reaction_created_v1<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<MessageReactionEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("im.message.reaction.created_v1", handler)
+    }
+
+    /// 删除消息表情回复
+    pub fn on_message_reaction_deleted_v1<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<MessageReactionEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("im.message.reaction.deleted_v1", handler)
+    }
+
+    /// 机器人进群
+    pub fn on_chat_member_bot_added_v1<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<ChatMemberBotEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("im.chat.member.bot.added_v1", handler)
+    }
+
+    /// 机器人被移出群
+    pub fn on_chat_member_bot_deleted_v1<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<ChatMemberBotEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("im.chat.member.bot.deleted_v1", handler)
+    }
+
+    /// 用户进群
+    pub fn on_chat_member_user_added_v1<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<ChatMemberUserEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("im.chat.member.user.added_v1", handler)
+    }
+
+    /// 撤销拉用户进群
+    pub fn on_chat_member_user_withdrawn_v1<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<ChatMemberUserEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("im.chat.member.user.withdrawn_v1", handler)
+    }
+
+    /// 用户出群
+    pub fn on_chat_member_user_deleted_v1<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<ChatMemberUserEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("im.chat.member.user.deleted_v1", handler)
+    }
+
+    /// 群配置修改
+    pub fn on_chat_updated_v1<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<ChatUpdatedEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("im.chat.updated_v1", handler)
+    }
+
+    /// 群解散
+    pub fn on_chat_disbanded_v1<F, Fut>(self, handler: F) -> Self
+    where
+        F: Fn(EventV2<ChatDisbandedEvent>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = SDKResult<()>> + Send + 'static,
+    {
+        self.register("im.chat.disbanded_v1", handler)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{Arc, Mutex};
+
+    use crate::{
+        event::{EventDispatcher, EventV2},
+        service::im::v1::event::{
+            ChatDisbandedEvent, ChatMemberBotEvent, ChatMemberUserEvent, ChatUpdatedEvent,
+            MessageReactionEvent, MessageReadEvent, MessageReceiveEvent,
+        },
+    };
+
+    macro_rules! fixture {
+        ($name:literal) => {
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/events/",
+                $name,
+                ".json"
+            ))
+        };
+    }
+
+    #[test]
+    fn test_message_receive_v1() {
+        let event: EventV2<MessageReceiveEvent> =
+            serde_json::from_str(fixture!("im_message_receive_v1")).unwrap();
+        assert_eq!(event.header.event_type, "im.message.receive_v1");
+        let message = event.event.message;
+        assert_eq!(message.chat_type, "group");
+        assert_eq!(message.content, r#"{"text":"@_user_1 hello"}"#);
+        let mentions = message.mentions.unwrap();
+        assert_eq!(mentions[0].key, "@_user_1");
+        assert_eq!(
+            event.event.sender.sender_id.open_id.as_deref(),
+            Some("ou_84aad35d084aa403a838cf73ee18467")
+        );
+    }
+
+    #[test]
+    fn test_message_read_v1() {
+        let event: EventV2<MessageReadEvent> =
+            serde_json::from_str(fixture!("im_message_message_read_v1")).unwrap();
+        assert_eq!(event.event.reader.read_time, "1609484183000");
+        assert_eq!(
+            event.event.message_id_list,
+            vec!["om_dc13264520392913993dd051dba21dcf"]
+        );
+    }
+
+    #[test]
+    fn test_message_reaction_created_v1() {
+        let event: EventV2<MessageReactionEvent> =
+            serde_json::from_str(fixture!("im_message_reaction_created_v1")).unwrap();
+        assert_eq!(event.event.reaction_type.emoji_type, "SMILE");
+        assert_eq!(event.event.operator_type, "user");
+        assert!(event.event.app_id.is_none());
+    }
+
+    #[test]
+    fn test_message_reaction_deleted_v1() {
+        let event: EventV2<MessageReactionEvent> =
+            serde_json::from_str(fixture!("im_message_reaction_deleted_v1")).unwrap();
+        assert_eq!(event.header.event_type, "im.message.reaction.deleted_v1");
+        assert_eq!(event.event.reaction_type.emoji_type, "THUMBSUP");
+        assert_eq!(event.event.operator_type, "app");
+        assert_eq!(event.event.app_id.as_deref(), Some("cli_9f5343c580712544"));
+        assert!(event.event.user_id.is_none());
+    }
+
+    #[test]
+    fn test_chat_member_bot_added_v1() {
+        let event: EventV2<ChatMemberBotEvent> =
+            serde_json::from_str(fixture!("im_chat_member_bot_added_v1")).unwrap();
+        assert!(!event.event.external);
+        assert_eq!(
+            event.event.i18n_names.unwrap().en_us.as_deref(),
+            Some("test group")
+        );
+    }
+
+    #[test]
+    fn test_chat_member_bot_deleted_v1() {
+        let event: EventV2<ChatMemberBotEvent> =
+            serde_json::from_str(fixture!("im_chat_member_bot_deleted_v1")).unwrap();
+        assert_eq!(event.header.event_type, "im.chat.member.bot.deleted_v1");
+        assert!(event.event.external);
+        assert_eq!(event.event.name.as_deref(), Some("外部群"));
+    }
+
+    #[test]
+    fn test_chat_member_user_added_v1() {
+        let event: EventV2<ChatMemberUserEvent> =
+            serde_json::from_str(fixture!("im_chat_member_user_added_v1")).unwrap();
+        assert_eq!(event.event.users.len(), 1);
+        assert_eq!(
+            event.event.users[0].user_id.user_id.as_deref(),
+            Some("4d7a3c6g")
+        );
+    }
+
+    #[test]
+    fn test_chat_member_user_withdrawn_v1() {
+        let event: EventV2<ChatMemberUserEvent> =
+            serde_json::from_str(fixture!("im_chat_member_user_withdrawn_v1")).unwrap();
+        assert_eq!(event.header.event_type, "im.chat.member.user.withdrawn_v1");
+        assert_eq!(event.event.users.len(), 2);
+        assert_eq!(event.event.users[1].name.as_deref(), Some("user_name_2"));
+    }
+
+    #[test]
+    fn test_chat_member_user_deleted_v1() {
+        let event: EventV2<ChatMemberUserEvent> =
+            serde_json::from_str(fixture!("im_chat_member_user_deleted_v1")).unwrap();
+        assert_eq!(event.header.event_type, "im.chat.member.user.deleted_v1");
+        assert_eq!(
+            event.event.users[1].user_id.open_id.as_deref(),
+            Some("ou_5ffa4ad9b5e6c1e5a5a5c46c5da6a2a3")
+        );
+    }
+
+    #[test]
+    fn test_chat_updated_v1() {
+        let event: EventV2<ChatUpdatedEvent> =
+            serde_json::from_str(fixture!("im_chat_updated_v1")).unwrap();
+        assert_eq!(event.event.after_change.name.as_deref(), Some("群聊新名称"));
+        assert_eq!(
+            event.event.before_change.name.as_deref(),
+            Some("群聊旧名称")
+        );
+        assert!(event.event.before_change.owner_id.is_none());
+        let moderators = event.event.moderator_list.unwrap();
+        assert_eq!(moderators.added_member_list.unwrap().len(), 1);
+        assert!(moderators.removed_member_list.is_none());
+    }
+
+    #[test]
+    fn test_chat_disbanded_v1() {
+        let event: EventV2<ChatDisbandedEvent> =
+            serde_json::from_str(fixture!("im_chat_disbanded_v1")).unwrap();
+        assert_eq!(event.event.chat_id, "oc_413871f9ac1b2e8e4b3b4b1ac1b2b1a1");
+        assert_eq!(event.event.name.as_deref(), Some("测试群"));
+    }
+
+    #[tokio::test]
+    async fn test_on_message_receive_v1() {
+        let received = Arc::new(Mutex::new(None));
+        let sink = Arc::clone(&received);
+        let dispatcher = EventDispatcher::new().on_message_receive_v1(move |event| {
+            let sink = Arc::clone(&sink);
+            async move {
+                *sink.lock().unwrap() = Some(event.event.message.message_id);
+                Ok(())
+            }
+        });
+
+        dispatcher
+            .dispatch(fixture!("im_message_receive_v1").as_bytes())
+            .await
+            .unwrap();
+        assert_eq!(
+            received.lock().unwrap().as_deref(),
+            Some("om_5ce6d572455d361153b7cb51da133945")
+        );
+    }
+}
@@ -5,14 +5,17 @@ use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
-use crate::core::{
-    api_req::ApiRequest,
-    api_resp::{ApiResponseTrait, BaseResponse, ResponseFormat},
-    config::Config,
-    constants::AccessTokenType,
-    http::Transport,
-    req_option::RequestOption,
-    SDKResult,
+use crate::{
+    core::{
+        api_req::ApiRequest,
+        api_resp::{ApiResponseTrait, BaseResponse, RawResponse, ResponseFormat},
+        config::Config,
+        constants::AccessTokenType,
+        http::Transport,
+        req_option::RequestOption,
+        SDKResult,
+    },
+    service::common::{EmojiType, MessageSortType, MsgType, ReceiveIdType, UserIdType},
 };
 
 pub struct MessageService {
@@ -62,7 +65,7 @@ impl MessageService {
         &self,
         list_message_request: ListMessageRequest,
         option: Option<RequestOption>,
-    ) -> ListMessageIterator {
+    ) -> ListMessageIterator<'_> {
         ListMessageIterator {
             service: self,
             req: list_message_request,
@@ -70,6 +73,215 @@ impl MessageService {
             has_more: true,
         }
     }
+
+    /// 回复消息
+    ///
+    /// 回复指定消息，支持文本、富文本、卡片、群名片、个人名片、图片、视频、文件等多种消息类型。
+    /// https://open.feishu.cn/document/server-docs/im-v1/message/reply
+    pub async fn reply(
+        &self,
+        reply_message_request: ReplyMessageRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<Message>> {
+        let mut api_req = reply_message_request.api_req;
+        api_req.http_method = Method::POST;
+        api_req.api_path = format!(
+            "/open-apis/im/v1/messages/{}/reply",
+            reply_message_request.message_id
+        );
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant, AccessTokenType::User];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    /// 编辑消息
+    ///
+    /// 编辑已发送的消息内容，仅支持文本和富文本消息。
+    /// https://open.feishu.cn/document/server-docs/im-v1/message/update
+    pub async fn update(
+        &self,
+        update_message_request: UpdateMessageRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<Message>> {
+        let mut api_req = update_message_request.api_req;
+        api_req.http_method = Method::PUT;
+        api_req.api_path = format!(
+            "/open-apis/im/v1/messages/{}",
+            update_message_request.message_id
+        );
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant, AccessTokenType::User];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    /// 更新应用发送的消息卡片
+    ///
+    /// 仅支持更新未撤回的卡片消息，卡片需开启“共享卡片”配置。
+    /// https://open.feishu.cn/document/server-docs/im-v1/message-card/patch
+    pub async fn patch(
+        &self,
+        patch_message_request: PatchMessageRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<RawResponse>> {
+        let mut api_req = patch_message_request.api_req;
+        api_req.http_method = Method::PATCH;
+        api_req.api_path = format!(
+            "/open-apis/im/v1/messages/{}",
+            patch_message_request.message_id
+        );
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant, AccessTokenType::User];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    /// 撤回消息
+    ///
+    /// 撤回指定消息，机器人只能撤回自己发送的消息。
+    /// https://open.feishu.cn/document/server-docs/im-v1/message/delete
+    pub async fn delete(
+        &self,
+        delete_message_request: DeleteMessageRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<RawResponse>> {
+        let mut api_req = delete_message_request.api_req;
+        api_req.http_method = Method::DELETE;
+        api_req.api_path = format!(
+            "/open-apis/im/v1/messages/{}",
+            delete_message_request.message_id
+        );
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant, AccessTokenType::User];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    /// 转发消息
+    ///
+    /// 将一条消息转发给用户、群聊或话题。
+    /// https://open.feishu.cn/document/server-docs/im-v1/message/forward
+    pub async fn forward(
+        &self,
+        forward_message_request: ForwardMessageRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<Message>> {
+        let mut api_req = forward_message_request.api_req;
+        api_req.http_method = Method::POST;
+        api_req.api_path = format!(
+            "/open-apis/im/v1/messages/{}/forward",
+            forward_message_request.message_id
+        );
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    /// 合并转发消息
+    ///
+    /// 将同一会话中的多条消息合并为一条消息转发。
+    /// https://open.feishu.cn/document/server-docs/im-v1/message/merge_forward
+    pub async fn merge_forward(
+        &self,
+        merge_forward_message_request: MergeForwardMessageRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<MergeForwardMessageRespData>> {
+        let mut api_req = merge_forward_message_request.api_req;
+        api_req.http_method = Method::POST;
+        api_req.api_path = "/open-apis/im/v1/messages/merge_forward".to_string();
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    /// 获取指定消息的内容
+    ///
+    /// 合并转发的消息会同时返回其中的子消息。
+    /// https://open.feishu.cn/document/server-docs/im-v1/message/get
+    pub async fn get(
+        &self,
+        get_message_request: GetMessageRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<GetMessageRespData>> {
+        let mut api_req = get_message_request.api_req;
+        api_req.http_method = Method::GET;
+        api_req.api_path = format!(
+            "/open-apis/im/v1/messages/{}",
+            get_message_request.message_id
+        );
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant, AccessTokenType::User];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    /// 发送应用内加急
+    ///
+    /// 对指定消息进行应用内加急，只能加急机器人自己发送的消息。
+    /// https://open.feishu.cn/document/server-docs/im-v1/buzz-messages/urgent_app
+    pub async fn urgent_app(
+        &self,
+        urgent_message_request: UrgentMessageRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<UrgentMessageRespData>> {
+        self.urgent("urgent_app", urgent_message_request, option)
+            .await
+    }
+
+    /// 发送短信加急
+    ///
+    /// 对指定消息进行应用内加急与短信加急，只能加急机器人自己发送的消息。
+    /// https://open.feishu.cn/document/server-docs/im-v1/buzz-messages/urgent_sms
+    pub async fn urgent_sms(
+        &self,
+        urgent_message_request: UrgentMessageRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<UrgentMessageRespData>> {
+        self.urgent("urgent_sms", urgent_message_request, option)
+            .await
+    }
+
+    /// 发送电话加急
+    ///
+    /// 对指定消息进行应用内加急与电话加急，只能加急机器人自己发送的消息。
+    /// https://open.feishu.cn/document/server-docs/im-v1/buzz-messages/urgent_phone
+    pub async fn urgent_phone(
+        &self,
+        urgent_message_request: UrgentMessageRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<UrgentMessageRespData>> {
+        self.urgent("urgent_phone", urgent_message_request, option)
+            .await
+    }
+
+    async fn urgent(
+        &self,
+        urgent_type: &str,
+        urgent_message_request: UrgentMessageRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<UrgentMessageRespData>> {
+        let mut api_req = urgent_message_request.api_req;
+        api_req.http_method = Method::PATCH;
+        api_req.api_path = format!(
+            "/open-apis/im/v1/messages/{}/{urgent_type}",
+            urgent_message_request.message_id
+        );
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
 }
 
 pub struct ListMessageIterator<'a> {
@@ -131,11 +343,11 @@ pub struct CreateMessageRequestBuilder {
 }
 
 impl CreateMessageRequestBuilder {
-    pub fn receive_id_type(mut self, receive_id_type: impl ToString) -> Self {
-        self.request
-            .api_req
-            .query_params
-            .insert("receive_id_type".to_string(), receive_id_type.to_string());
+    pub fn receive_id_type(mut self, receive_id_type: impl Into<ReceiveIdType>) -> Self {
+        self.request.api_req.query_params.insert(
+            "receive_id_type".to_string(),
+            receive_id_type.into().to_string(),
+        );
         self
     }
 
@@ -203,12 +415,19 @@ impl CreateMessageRequestBodyBuilder {
         self
     }
 
+    /// 消息内容，同时设置消息类型
+    pub fn message(mut self, message: impl SendMessageTrait) -> Self {
+        self.request.msg_type = message.msg_type();
+        self.request.content = message.content();
+        self
+    }
+
     /// 消息类型 包括：text、post、image、file、audio、media、sticker、interactive、share_chat、
     /// share_user等，类型定义请参考发送消息内容
     ///
     /// 示例值："text"
-    pub fn msg_type(mut self, msg_type: impl ToString) -> Self {
-        self.request.msg_type = msg_type.to_string();
+    pub fn msg_type(mut self, msg_type: impl Into<MsgType>) -> Self {
+        self.request.msg_type = msg_type.into().to_string();
         self
     }
 
@@ -238,213 +457,835 @@ impl CreateMessageRequestBodyBuilder {
         self
     }
 
-    pub fn build(self) -> CreateMessageRequestBody {
+    pub fn build(self) -> CreateMessageRequestBody {
+        self.request
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreateMessageResp {
+    pub data: Message,
+}
+
+/// 消息类型
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Message {
+    /// 消息id
+    pub message_id: String,
+    /// 根消息id，用于回复消息场景
+    pub root_id: Option<String>,
+    /// 父消息的id，用于回复消息场景
+    pub parent_id: Option<String>,
+    /// 消息所属的话题 ID（不返回说明该消息非话题消息）
+    pub thread_id: Option<String>,
+    /// 消息类型 包括：text、post、image、file、audio、media、sticker、interactive、share_chat、
+    /// share_user等
+    pub msg_type: String,
+    /// 消息生成的时间戳（毫秒）
+    pub create_time: String,
+    /// 消息更新的时间戳（毫秒）
+    pub update_time: String,
+    /// 消息是否被撤回
+    pub deleted: bool,
+    /// 消息是否被更新
+    pub updated: bool,
+    /// 所属的群
+    pub chat_id: String,
+    /// 发送者，可以是用户或应用
+    pub sender: Sender,
+    /// 消息内容
+    pub body: MessageBody,
+    /// 被@的用户或机器人的id列表
+    pub mentions: Option<Vec<Mention>>,
+}
+
+impl ApiResponseTrait for Message {
+    fn data_format() -> ResponseFormat {
+        ResponseFormat::Data
+    }
+}
+
+/// 发送者，可以是用户或应用
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Sender {
+    /// 该字段标识发送者的id
+    id: String,
+    /// 该字段标识发送者的id类型
+    ///
+    /// 可选值有：
+    /// - open_id
+    /// - app_id
+    id_type: String,
+    /// 该字段标识发送者的类型
+    ///
+    /// 可选值有：
+    /// - user: 用户
+    /// - app: 应用
+    /// - anonymous: 匿名
+    /// - unknown: 未知
+    sender_type: String,
+    /// 为租户在飞书上的唯一标识，用来换取对应的tenant_access_token，
+    /// 也可以用作租户在应用里面的唯一标识
+    tenant_key: String,
+}
+
+/// 消息内容
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MessageBody {
+    /// 消息内容，json结构序列化后的字符串。不同msg_type对应不同内容。
+    ///
+    /// 消息类型 包括：text、post、image、file、audio、media、sticker、interactive、share_chat、
+    /// share_user等，
+    pub content: String,
+}
+
+/// 被@的用户或机器人的id列表
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Mention {
+    /// 被@的用户或机器人的序号。例如，第3个被@到的成员，值为“@_user_3”
+    pub key: String,
+    /// 被@的用户或者机器人的open_id
+    pub id: String,
+    /// 被@的用户或机器人 id 类型，目前仅支持 open_id
+    pub id_type: String,
+    /// 被@的用户或机器人的姓名
+    pub name: String,
+    /// 为租户在飞书上的唯一标识，用来换取对应的tenant_access_token，
+    /// 也可以用作租户在应用里面的唯一标识
+    pub tenant_key: String,
+    /// 合并转发消息中，上一层级的消息id message_id
+    pub upper_message_id: String,
+}
+
+#[derive(Default, Clone)]
+pub struct ListMessageRequest {
+    api_req: ApiRequest,
+}
+impl ListMessageRequest {
+    pub fn builder() -> ListMessageRequestBuilder {
+        ListMessageRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct ListMessageRequestBuilder {
+    request: ListMessageRequest,
+}
+
+impl ListMessageRequestBuilder {
+    /// 容器类型 ，目前可选值仅有"chat"，包含单聊（p2p）和群聊（group）
+    ///
+    /// 示例值：chat
+    pub fn container_id_type(mut self, container_id_type: impl ToString) -> Self {
+        self.request.api_req.query_params.insert(
+            "container_id_type".to_string(),
+            container_id_type.to_string(),
+        );
+        self
+    }
+
+    /// 容器的id，即chat的id，详情参见[群ID 说明](https://open.feishu.cn/document/uAjLw4CM/ukTMukTMukTM/reference/im-v1/chat-id-description)
+    ///
+    /// 示例值：oc_234jsi43d3ssi993d43545f
+    pub fn container_id(mut self, container_id: impl ToString) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("container_id".to_string(), container_id.to_string());
+        self
+    }
+
+    /// 历史信息的起始时间（秒级时间戳）
+    ///
+    /// 示例值：1609296809
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("start_time".to_string(), start_time.to_string());
+        self
+    }
+
+    /// 历史信息的结束时间（秒级时间戳）
+    ///
+    /// 示例值：1608594809
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("end_time".to_string(), end_time.to_string());
+        self
+    }
+
+    /// 消息排序方式
+    ///
+    /// 示例值：ByCreateTimeAsc
+    pub fn sort_type(mut self, sort_type: impl Into<MessageSortType>) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("sort_type".to_string(), sort_type.into().to_string());
+        self
+    }
+
+    /// 分页标记，第一次请求不填，表示从头开始遍历；分页查询结果还有更多项时会同时返回新的
+    /// page_token，下次遍历可采用该 page_token 获取查询结果
+    pub fn page_token(mut self, page_token: impl ToString) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("page_token".to_string(), page_token.to_string());
+        self
+    }
+
+    /// 分页大小
+    ///
+    /// 示例值：20
+    pub fn page_size(mut self, page_size: i32) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("page_size".to_string(), page_size.to_string());
+        self
+    }
+
+    pub fn build(self) -> ListMessageRequest {
+        self.request
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListMessageRespData {
+    /// 是否还有更多项
+    pub has_more: bool,
+    /// 分页标记，当 has_more 为 true 时，会同时返回新的 page_token，否则不返回 page_token
+    pub page_token: Option<String>,
+    pub items: Vec<Message>,
+}
+
+impl ApiResponseTrait for ListMessageRespData {
+    fn data_format() -> ResponseFormat {
+        ResponseFormat::Data
+    }
+}
+
+/// 回复消息请求
+#[derive(Default)]
+pub struct ReplyMessageRequest {
+    api_req: ApiRequest,
+    /// 待回复的消息的ID
+    message_id: String,
+}
+
+impl ReplyMessageRequest {
+    pub fn builder() -> ReplyMessageRequestBuilder {
+        ReplyMessageRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct ReplyMessageRequestBuilder {
+    request: ReplyMessageRequest,
+}
+
+impl ReplyMessageRequestBuilder {
+    /// 待回复的消息的ID
+    ///
+    /// 示例值："om_dc13264520392913993dd051dba21dcf"
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id = message_id.to_string();
+        self
+    }
+
+    pub fn request_body(mut self, body: ReplyMessageRequestBody) -> Self {
+        self.request.api_req.body = serde_json::to_vec(&body).unwrap();
+        self
+    }
+
+    pub fn build(self) -> ReplyMessageRequest {
+        self.request
+    }
+}
+
+/// 回复消息 请求体
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ReplyMessageRequestBody {
+    /// 消息内容，JSON结构序列化后的字符串，格式与发送消息相同
+    content: String,
+    /// 消息类型，与发送消息相同
+    msg_type: String,
+    /// 是否以话题形式回复；若要回复的消息已经是话题消息，则默认以话题形式进行回复
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reply_in_thread: Option<bool>,
+    /// 由开发者生成的唯一字符串序列，用于回复消息请求去重；
+    /// 持有相同uuid的请求1小时内至多成功执行一次
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uuid: Option<String>,
+}
+
+impl ReplyMessageRequestBody {
+    pub fn builder() -> ReplyMessageRequestBodyBuilder {
+        ReplyMessageRequestBodyBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct ReplyMessageRequestBodyBuilder {
+    request: ReplyMessageRequestBody,
+}
+
+impl ReplyMessageRequestBodyBuilder {
+    /// 消息内容，同时设置消息类型
+    pub fn message(mut self, message: impl SendMessageTrait) -> Self {
+        self.request.msg_type = message.msg_type();
+        self.request.content = message.content();
+        self
+    }
+
+    /// 消息内容，JSON结构序列化后的字符串
+    pub fn content(mut self, content: impl ToString) -> Self {
+        self.request.content = content.to_string();
+        self
+    }
+
+    /// 消息类型
+    pub fn msg_type(mut self, msg_type: impl Into<MsgType>) -> Self {
+        self.request.msg_type = msg_type.into().to_string();
+        self
+    }
+
+    /// 是否以话题形式回复
+    ///
+    /// 示例值：false
+    pub fn reply_in_thread(mut self, reply_in_thread: bool) -> Self {
+        self.request.reply_in_thread = Some(reply_in_thread);
+        self
+    }
+
+    /// 由开发者生成的唯一字符串序列，用于回复消息请求去重
+    ///
+    /// 数据校验规则：
+    ///
+    /// 最大长度：50 字符
+    pub fn uuid(mut self, uuid: impl ToString) -> Self {
+        self.request.uuid = Some(uuid.to_string());
+        self
+    }
+
+    pub fn build(self) -> ReplyMessageRequestBody {
+        self.request
+    }
+}
+
+/// 编辑消息请求
+#[derive(Default)]
+pub struct UpdateMessageRequest {
+    api_req: ApiRequest,
+    /// 待编辑的消息的ID
+    message_id: String,
+}
+
+impl UpdateMessageRequest {
+    pub fn builder() -> UpdateMessageRequestBuilder {
+        UpdateMessageRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct UpdateMessageRequestBuilder {
+    request: UpdateMessageRequest,
+}
+
+impl UpdateMessageRequestBuilder {
+    /// 待编辑的消息的ID，仅支持编辑文本和富文本消息
+    ///
+    /// 示例值："om_dc13264520392913993dd051dba21dcf"
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id = message_id.to_string();
+        self
+    }
+
+    pub fn request_body(mut self, body: UpdateMessageRequestBody) -> Self {
+        self.request.api_req.body = serde_json::to_vec(&body).unwrap();
+        self
+    }
+
+    pub fn build(self) -> UpdateMessageRequest {
+        self.request
+    }
+}
+
+/// 编辑消息 请求体
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct UpdateMessageRequestBody {
+    /// 消息类型，仅支持 text 和 post
+    msg_type: String,
+    /// 消息内容，JSON结构序列化后的字符串
+    content: String,
+}
+
+impl UpdateMessageRequestBody {
+    pub fn builder() -> UpdateMessageRequestBodyBuilder {
+        UpdateMessageRequestBodyBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct UpdateMessageRequestBodyBuilder {
+    request: UpdateMessageRequestBody,
+}
+
+impl UpdateMessageRequestBodyBuilder {
+    /// 消息内容，同时设置消息类型
+    pub fn message(mut self, message: impl SendMessageTrait) -> Self {
+        self.request.msg_type = message.msg_type();
+        self.request.content = message.content();
+        self
+    }
+
+    /// 消息类型，仅支持 text 和 post
+    pub fn msg_type(mut self, msg_type: impl Into<MsgType>) -> Self {
+        self.request.msg_type = msg_type.into().to_string();
+        self
+    }
+
+    /// 消息内容，JSON结构序列化后的字符串
+    pub fn content(mut self, content: impl ToString) -> Self {
+        self.request.content = content.to_string();
+        self
+    }
+
+    pub fn build(self) -> UpdateMessageRequestBody {
+        self.request
+    }
+}
+
+/// 更新消息卡片请求
+#[derive(Default)]
+pub struct PatchMessageRequest {
+    api_req: ApiRequest,
+    /// 待更新的卡片消息的ID
+    message_id: String,
+}
+
+impl PatchMessageRequest {
+    pub fn builder() -> PatchMessageRequestBuilder {
+        PatchMessageRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct PatchMessageRequestBuilder {
+    request: PatchMessageRequest,
+}
+
+impl PatchMessageRequestBuilder {
+    /// 待更新的卡片消息的ID
+    ///
+    /// 示例值："om_dc13264520392913993dd051dba21dcf"
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id = message_id.to_string();
+        self
+    }
+
+    pub fn request_body(mut self, body: PatchMessageRequestBody) -> Self {
+        self.request.api_req.body = serde_json::to_vec(&body).unwrap();
+        self
+    }
+
+    pub fn build(self) -> PatchMessageRequest {
+        self.request
+    }
+}
+
+/// 更新消息卡片 请求体
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct PatchMessageRequestBody {
+    /// 卡片内容，JSON结构序列化后的字符串
+    content: String,
+}
+
+impl PatchMessageRequestBody {
+    pub fn builder() -> PatchMessageRequestBodyBuilder {
+        PatchMessageRequestBodyBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct PatchMessageRequestBodyBuilder {
+    request: PatchMessageRequestBody,
+}
+
+impl PatchMessageRequestBodyBuilder {
+    /// 卡片内容，飞书卡片或卡片模板
+    pub fn message(mut self, message: impl CardMessageTrait) -> Self {
+        self.request.content = message.content();
+        self
+    }
+
+    /// 卡片内容，JSON结构序列化后的字符串
+    pub fn content(mut self, content: impl ToString) -> Self {
+        self.request.content = content.to_string();
+        self
+    }
+
+    pub fn build(self) -> PatchMessageRequestBody {
+        self.request
+    }
+}
+
+/// 撤回消息请求
+#[derive(Default)]
+pub struct DeleteMessageRequest {
+    api_req: ApiRequest,
+    /// 待撤回的消息的ID
+    message_id: String,
+}
+
+impl DeleteMessageRequest {
+    pub fn builder() -> DeleteMessageRequestBuilder {
+        DeleteMessageRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct DeleteMessageRequestBuilder {
+    request: DeleteMessageRequest,
+}
+
+impl DeleteMessageRequestBuilder {
+    /// 待撤回的消息的ID
+    ///
+    /// 示例值："om_dc13264520392913993dd051dba21dcf"
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id = message_id.to_string();
+        self
+    }
+
+    pub fn build(self) -> DeleteMessageRequest {
+        self.request
+    }
+}
+
+/// 转发消息请求
+#[derive(Default)]
+pub struct ForwardMessageRequest {
+    api_req: ApiRequest,
+    /// 待转发的消息的ID
+    message_id: String,
+}
+
+impl ForwardMessageRequest {
+    pub fn builder() -> ForwardMessageRequestBuilder {
+        ForwardMessageRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct ForwardMessageRequestBuilder {
+    request: ForwardMessageRequest,
+}
+
+impl ForwardMessageRequestBuilder {
+    /// 待转发的消息的ID
+    ///
+    /// 示例值："om_dc13264520392913993dd051dba21dcf"
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id = message_id.to_string();
+        self
+    }
+
+    /// 消息接收者id类型，支持 open_id、union_id、user_id、email、chat_id 和 thread_id
+    pub fn receive_id_type(mut self, receive_id_type: impl Into<ReceiveIdType>) -> Self {
+        self.request.api_req.query_params.insert(
+            "receive_id_type".to_string(),
+            receive_id_type.into().to_string(),
+        );
+        self
+    }
+
+    /// 由开发者生成的唯一字符串序列，用于转发消息请求去重；
+    /// 持有相同uuid的请求在1小时内向同一个目标的转发只可成功一次
+    pub fn uuid(mut self, uuid: impl ToString) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("uuid".to_string(), uuid.to_string());
+        self
+    }
+
+    pub fn request_body(mut self, body: ForwardMessageRequestBody) -> Self {
+        self.request.api_req.body = serde_json::to_vec(&body).unwrap();
+        self
+    }
+
+    pub fn build(self) -> ForwardMessageRequest {
+        self.request
+    }
+}
+
+/// 转发消息 请求体
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct ForwardMessageRequestBody {
+    /// 消息接收者的ID，ID类型应与查询参数receive_id_type 对应
+    ///
+    /// 示例值："oc_a0553eda9014c201e6969b478895c230"
+    receive_id: String,
+}
+
+impl ForwardMessageRequestBody {
+    pub fn new(receive_id: impl ToString) -> Self {
+        Self {
+            receive_id: receive_id.to_string(),
+        }
+    }
+}
+
+/// 合并转发消息请求
+#[derive(Default)]
+pub struct MergeForwardMessageRequest {
+    api_req: ApiRequest,
+}
+
+impl MergeForwardMessageRequest {
+    pub fn builder() -> MergeForwardMessageRequestBuilder {
+        MergeForwardMessageRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct MergeForwardMessageRequestBuilder {
+    request: MergeForwardMessageRequest,
+}
+
+impl MergeForwardMessageRequestBuilder {
+    /// 消息接收者id类型，支持 open_id、union_id、user_id、email、chat_id 和 thread_id
+    pub fn receive_id_type(mut self, receive_id_type: impl Into<ReceiveIdType>) -> Self {
+        self.request.api_req.query_params.insert(
+            "receive_id_type".to_string(),
+            receive_id_type.into().to_string(),
+        );
+        self
+    }
+
+    /// 由开发者生成的唯一字符串序列，用于转发消息请求去重
+    pub fn uuid(mut self, uuid: impl ToString) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("uuid".to_string(), uuid.to_string());
+        self
+    }
+
+    pub fn request_body(mut self, body: MergeForwardMessageRequestBody) -> Self {
+        self.request.api_req.body = serde_json::to_vec(&body).unwrap();
+        self
+    }
+
+    pub fn build(self) -> MergeForwardMessageRequest {
+        self.request
+    }
+}
+
+/// 合并转发消息 请求体
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MergeForwardMessageRequestBody {
+    /// 消息接收者的ID，ID类型应与查询参数receive_id_type 对应
+    receive_id: String,
+    /// 要转发的消息ID列表，消息需属于同一个会话，按发送时间排序
+    message_id_list: Vec<String>,
+}
+
+impl MergeForwardMessageRequestBody {
+    pub fn builder() -> MergeForwardMessageRequestBodyBuilder {
+        MergeForwardMessageRequestBodyBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct MergeForwardMessageRequestBodyBuilder {
+    request: MergeForwardMessageRequestBody,
+}
+
+impl MergeForwardMessageRequestBodyBuilder {
+    /// 消息接收者的ID，ID类型应与查询参数receive_id_type 对应
+    ///
+    /// 示例值："oc_a0553eda9014c201e6969b478895c230"
+    pub fn receive_id(mut self, receive_id: impl ToString) -> Self {
+        self.request.receive_id = receive_id.to_string();
+        self
+    }
+
+    /// 要转发的消息ID列表
+    ///
+    /// 数据校验规则：
+    ///
+    /// 长度范围：1 ～ 100
+    pub fn message_id_list(mut self, message_id_list: Vec<String>) -> Self {
+        self.request.message_id_list = message_id_list;
+        self
+    }
+
+    /// 追加一条要转发的消息ID
+    pub fn add_message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id_list.push(message_id.to_string());
+        self
+    }
+
+    pub fn build(self) -> MergeForwardMessageRequestBody {
         self.request
     }
 }
 
+/// 合并转发消息 响应体
 #[derive(Debug, Serialize, Deserialize)]
-pub struct CreateMessageResp {
-    pub data: Message,
-}
-
-/// 消息类型
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Message {
-    /// 消息id
-    pub message_id: String,
-    /// 根消息id，用于回复消息场景
-    pub root_id: Option<String>,
-    /// 父消息的id，用于回复消息场景
-    pub parent_id: Option<String>,
-    /// 消息所属的话题 ID（不返回说明该消息非话题消息）
-    pub thread_id: Option<String>,
-    /// 消息类型 包括：text、post、image、file、audio、media、sticker、interactive、share_chat、
-    /// share_user等
-    pub msg_type: String,
-    /// 消息生成的时间戳（毫秒）
-    pub create_time: String,
-    /// 消息更新的时间戳（毫秒）
-    pub update_time: String,
-    /// 消息是否被撤回
-    pub deleted: bool,
-    /// 消息是否被更新
-    pub updated: bool,
-    /// 所属的群
-    pub chat_id: String,
-    /// 发送者，可以是用户或应用
-    pub sender: Sender,
-    /// 消息内容
-    pub body: MessageBody,
-    /// 被@的用户或机器人的id列表
-    pub mentions: Option<Vec<Mention>>,
+pub struct MergeForwardMessageRespData {
+    /// 合并转发生成的新消息
+    pub message: Message,
+    /// 无效的消息ID列表
+    #[serde(default)]
+    pub invalid_message_id_list: Vec<String>,
 }
 
-impl ApiResponseTrait for Message {
+impl ApiResponseTrait for MergeForwardMessageRespData {
     fn data_format() -> ResponseFormat {
         ResponseFormat::Data
     }
 }
 
-/// 发送者，可以是用户或应用
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Sender {
-    /// 该字段标识发送者的id
-    id: String,
-    /// 该字段标识发送者的id类型
-    ///
-    /// 可选值有：
-    /// - open_id
-    /// - app_id
-    id_type: String,
-    /// 该字段标识发送者的类型
-    ///
-    /// 可选值有：
-    /// - user: 用户
-    /// - app: 应用
-    /// - anonymous: 匿名
-    /// - unknown: 未知
-    sender_type: String,
-    /// 为租户在飞书上的唯一标识，用来换取对应的tenant_access_token，
-    /// 也可以用作租户在应用里面的唯一标识
-    tenant_key: String,
-}
-
-/// 消息内容
-#[derive(Debug, Serialize, Deserialize)]
-pub struct MessageBody {
-    /// 消息内容，json结构序列化后的字符串。不同msg_type对应不同内容。
-    ///
-    /// 消息类型 包括：text、post、image、file、audio、media、sticker、interactive、share_chat、
-    /// share_user等，
-    pub content: String,
-}
-
-/// 被@的用户或机器人的id列表
-#[derive(Debug, Serialize, Deserialize)]
-pub struct Mention {
-    /// 被@的用户或机器人的序号。例如，第3个被@到的成员，值为“@_user_3”
-    pub key: String,
-    /// 被@的用户或者机器人的open_id
-    pub id: String,
-    /// 被@的用户或机器人 id 类型，目前仅支持 open_id
-    pub id_type: String,
-    /// 被@的用户或机器人的姓名
-    pub name: String,
-    /// 为租户在飞书上的唯一标识，用来换取对应的tenant_access_token，
-    /// 也可以用作租户在应用里面的唯一标识
-    pub tenant_key: String,
-    /// 合并转发消息中，上一层级的消息id message_id
-    pub upper_message_id: String,
-}
-
-#[derive(Default, Clone)]
-pub struct ListMessageRequest {
+/// 获取指定消息的内容请求
+#[derive(Default)]
+pub struct GetMessageRequest {
     api_req: ApiRequest,
+    /// 待获取的消息的ID
+    message_id: String,
 }
-impl ListMessageRequest {
-    pub fn builder() -> ListMessageRequestBuilder {
-        ListMessageRequestBuilder::default()
+
+impl GetMessageRequest {
+    pub fn builder() -> GetMessageRequestBuilder {
+        GetMessageRequestBuilder::default()
     }
 }
 
 #[derive(Default)]
-pub struct ListMessageRequestBuilder {
-    request: ListMessageRequest,
+pub struct GetMessageRequestBuilder {
+    request: GetMessageRequest,
 }
 
-impl ListMessageRequestBuilder {
-    /// 容器类型 ，目前可选值仅有"chat"，包含单聊（p2p）和群聊（group）
+impl GetMessageRequestBuilder {
+    /// 待获取的消息的ID
     ///
-    /// 示例值：chat
-    pub fn container_id_type(mut self, container_id_type: impl ToString) -> Self {
-        self.request.api_req.query_params.insert(
-            "container_id_type".to_string(),
-            container_id_type.to_string(),
-        );
+    /// 示例值："om_dc13264520392913993dd051dba21dcf"
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id = message_id.to_string();
         self
     }
 
-    /// 容器的id，即chat的id，详情参见[群ID 说明](https://open.feishu.cn/document/uAjLw4CM/ukTMukTMukTM/reference/im-v1/chat-id-description)
-    ///
-    /// 示例值：oc_234jsi43d3ssi993d43545f
-    pub fn container_id(mut self, container_id: impl ToString) -> Self {
+    /// 用户 ID 类型，用于消息中 @ 的用户
+    pub fn user_id_type(mut self, user_id_type: impl Into<UserIdType>) -> Self {
         self.request
             .api_req
             .query_params
-            .insert("container_id".to_string(), container_id.to_string());
+            .insert("user_id_type".to_string(), user_id_type.into().to_string());
         self
     }
 
-    /// 历史信息的起始时间（秒级时间戳）
-    ///
-    /// 示例值：1609296809
-    pub fn start_time(mut self, start_time: i64) -> Self {
+    pub fn build(self) -> GetMessageRequest {
         self.request
-            .api_req
-            .query_params
-            .insert("start_time".to_string(), start_time.to_string());
-        self
     }
+}
 
-    /// 历史信息的结束时间（秒级时间戳）
+/// 获取指定消息的内容 响应体
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetMessageRespData {
+    /// 消息列表，合并转发的消息会同时返回其子消息
+    pub items: Vec<Message>,
+}
+
+impl ApiResponseTrait for GetMessageRespData {
+    fn data_format() -> ResponseFormat {
+        ResponseFormat::Data
+    }
+}
+
+/// 消息加急请求, 应用内、短信和电话加急共用
+#[derive(Default)]
+pub struct UrgentMessageRequest {
+    api_req: ApiRequest,
+    /// 待加急的消息ID
+    message_id: String,
+}
+
+impl UrgentMessageRequest {
+    pub fn builder() -> UrgentMessageRequestBuilder {
+        UrgentMessageRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct UrgentMessageRequestBuilder {
+    request: UrgentMessageRequest,
+    body: UrgentMessageRequestBody,
+}
+
+impl UrgentMessageRequestBuilder {
+    /// 待加急的消息ID, 不支持批量消息ID
     ///
-    /// 示例值：1608594809
-    pub fn end_time(mut self, end_time: i64) -> Self {
-        self.request
-            .api_req
-            .query_params
-            .insert("end_time".to_string(), end_time.to_string());
+    /// 示例值："om_dc13264520392913993dd051dba21dcf"
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id = message_id.to_string();
         self
     }
 
-    /// 消息排序方式
-    ///
-    /// 示例值：ByCreateTimeAsc
-    pub fn sort_type(mut self, sort_type: impl ToString) -> Self {
+    /// 用户 ID 类型，与 user_id_list 中的 ID 类型对应, 默认为 open_id
+    pub fn user_id_type(mut self, user_id_type: impl Into<UserIdType>) -> Self {
         self.request
             .api_req
             .query_params
-            .insert("sort_type".to_string(), sort_type.to_string());
+            .insert("user_id_type".to_string(), user_id_type.into().to_string());
         self
     }
 
-    /// 分页标记，第一次请求不填，表示从头开始遍历；分页查询结果还有更多项时会同时返回新的
-    /// page_token，下次遍历可采用该 page_token 获取查询结果
-    pub fn page_token(mut self, page_token: impl ToString) -> Self {
-        self.request
-            .api_req
-            .query_params
-            .insert("page_token".to_string(), page_token.to_string());
+    /// 加急的目标用户ID列表, 用户需在消息所在的会话中
+    pub fn user_id_list(mut self, user_id_list: Vec<String>) -> Self {
+        self.body.user_id_list = user_id_list;
         self
     }
 
-    /// 分页大小
-    ///
-    /// 示例值：20
-    pub fn page_size(mut self, page_size: i32) -> Self {
-        self.request
-            .api_req
-            .query_params
-            .insert("page_size".to_string(), page_size.to_string());
+    /// 追加一个加急的目标用户ID
+    pub fn add_user_id(mut self, user_id: impl ToString) -> Self {
+        self.body.user_id_list.push(user_id.to_string());
         self
     }
 
-    pub fn build(self) -> ListMessageRequest {
+    pub fn build(mut self) -> UrgentMessageRequest {
+        // user_id_type 为必填的查询参数
+        self.request
+            .api_req
+            .query_params
+            .entry("user_id_type".to_string())
+            .or_insert_with(|| UserIdType::OpenId.to_string());
+        self.request.api_req.body = serde_json::to_vec(&self.body).unwrap();
         self.request
     }
 }
 
+/// 消息加急 请求体
+#[derive(Debug, Default, Serialize)]
+struct UrgentMessageRequestBody {
+    /// 加急的目标用户ID列表
+    user_id_list: Vec<String>,
+}
+
+/// 消息加急 响应体
 #[derive(Debug, Serialize, Deserialize)]
-pub struct ListMessageRespData {
-    /// 是否还有更多项
-    pub has_more: bool,
-    /// 分页标记，当 has_more 为 true 时，会同时返回新的 page_token，否则不返回 page_token
-    pub page_token: Option<String>,
-    pub items: Vec<Message>,
+pub struct UrgentMessageRespData {
+    /// 无效的用户ID列表, 这些用户不会收到加急
+    #[serde(default)]
+    pub invalid_user_id_list: Vec<String>,
 }
 
-impl ApiResponseTrait for ListMessageRespData {
+impl ApiResponseTrait for UrgentMessageRespData {
     fn data_format() -> ResponseFormat {
         ResponseFormat::Data
     }
@@ -455,6 +1296,9 @@ pub trait SendMessageTrait {
     fn content(&self) -> String;
 }
 
+/// 卡片消息, 即 msg_type 为 interactive 的消息, 更新消息卡片时只接受卡片
+pub trait CardMessageTrait: SendMessageTrait {}
+
 /// 文本 text
 pub struct MessageText {
     text: String,
@@ -710,13 +1554,13 @@ impl MediaNode {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EmotionNode {
     /// 表情类型，部分可选值请参见表情文案。
-    emoji_type: String,
+    emoji_type: EmojiType,
 }
 
 impl EmotionNode {
-    pub fn new(emoji_type: &str) -> Self {
+    pub fn new(emoji_type: impl Into<EmojiType>) -> Self {
         Self {
-            emoji_type: emoji_type.to_string(),
+            emoji_type: emoji_type.into(),
         }
     }
 }
@@ -726,6 +1570,14 @@ pub struct MessageImage {
     pub image_key: String,
 }
 
+impl MessageImage {
+    pub fn new(image_key: impl ToString) -> Self {
+        Self {
+            image_key: image_key.to_string(),
+        }
+    }
+}
+
 impl SendMessageTrait for MessageImage {
     fn msg_type(&self) -> String {
         "image".to_string()
@@ -736,6 +1588,30 @@ impl SendMessageTrait for MessageImage {
     }
 }
 
+/// 分享群名片消息
+pub struct MessageShareChat {
+    /// 群 ID
+    pub chat_id: String,
+}
+
+impl MessageShareChat {
+    pub fn new(chat_id: impl ToString) -> Self {
+        Self {
+            chat_id: chat_id.to_string(),
+        }
+    }
+}
+
+impl SendMessageTrait for MessageShareChat {
+    fn msg_type(&self) -> String {
+        "share_chat".to_string()
+    }
+
+    fn content(&self) -> String {
+        json!({"chat_id": self.chat_id}).to_string()
+    }
+}
+
 /// 卡片模板
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MessageCardTemplate {
@@ -755,6 +1631,8 @@ impl SendMessageTrait for MessageCardTemplate {
     }
 }
 
+impl CardMessageTrait for MessageCardTemplate {}
+
 impl MessageCardTemplate {
     pub fn new(template_id: impl ToString, template_variable: Value) -> Self {
         Self {
@@ -778,12 +1656,57 @@ struct CardTemplate {
 
 #[cfg(test)]
 mod test {
-    use serde_json::json;
-
-    use crate::service::im::v1::message::{
-        ANode, AtNode, EmotionNode, ImgNode, MediaNode, MessageText, SendMessageTrait, TextNode,
+    use std::sync::{Arc, Mutex};
+
+    use serde_json::{json, Value};
+    use tokio::net::TcpListener;
+
+    use crate::{
+        card::FeishuCard,
+        core::{
+            config::Config,
+            constants::Domain,
+            req_option::RequestOption,
+            test_http::{read_request, write_json},
+        },
+        service::{
+            common::{EmojiType, UserIdType},
+            im::v1::message::{
+                ANode, AtNode, CreateMessageRequestBody, DeleteMessageRequest, EmotionNode,
+                ForwardMessageRequest, GetMessageRequest, ImgNode, MediaNode,
+                MergeForwardMessageRequest, MergeForwardMessageRequestBody, MessageCardTemplate,
+                MessageService, MessageText, PatchMessageRequest, PatchMessageRequestBody,
+                ReplyMessageRequest, ReplyMessageRequestBody, SendMessageTrait, TextNode,
+                UpdateMessageRequest, UpdateMessageRequestBody, UrgentMessageRequest,
+                UrgentMessageRespData,
+            },
+        },
     };
 
+    /// 本地开放接口, 记录每个请求的方法和路径, 均返回成功
+    async fn api_server(count: usize) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let routes = Arc::new(Mutex::new(vec![]));
+        let received = Arc::clone(&routes);
+
+        tokio::spawn(async move {
+            for _ in 0..count {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let request = read_request(&mut stream).await.unwrap();
+                received
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} {}", request.method, request.path));
+
+                let body = json!({"code": 0, "msg": "success"});
+                write_json(&mut stream, "200 OK", &body).await.unwrap();
+            }
+        });
+
+        (base_url, routes)
+    }
+
     #[test]
     fn test_message_text() {
         let t1 = MessageText::new("").add_text(" test content").build();
@@ -816,6 +1739,7 @@ mod test {
                 MessagePostNode::Img(ImgNode::new("image_key")),
                 MessagePostNode::Media(MediaNode::new("file_key", Some("image_key"))),
                 MessagePostNode::Emotion(EmotionNode::new("SMILE")),
+                MessagePostNode::Emotion(EmotionNode::new(EmojiType::ThumbsUp)),
             ]);
         assert_eq!(post.msg_type(), "post");
         assert_eq!(
@@ -824,7 +1748,7 @@ mod test {
             "post": {
             "zh_cn": {
                 "title":"title",
-                "content": [[{"tag":"text","text":"text"},{"tag":"a","text":"text","href":"https://www.feishu.cn"},{"tag":"at","user_id":"user_id"},{"tag":"img","image_key":"image_key"},{"tag":"media","file_key":"file_key","image_key":"image_key"},{"tag":"emotion","emoji_type":"SMILE"}
+                "content": [[{"tag":"text","text":"text"},{"tag":"a","text":"text","href":"https://www.feishu.cn"},{"tag":"at","user_id":"user_id"},{"tag":"img","image_key":"image_key"},{"tag":"media","file_key":"file_key","image_key":"image_key"},{"tag":"emotion","emoji_type":"SMILE"},{"tag":"emotion","emoji_type":"THUMBSUP"}
                 ]]
             }}})
         );
@@ -842,4 +1766,190 @@ mod test {
             json!({"image_key": "image_key"}).to_string()
         );
     }
+
+    #[test]
+    fn test_message_requests() {
+        let body = CreateMessageRequestBody::builder()
+            .receive_id("oc_xxx")
+            .message(MessageText::new("hello"))
+            .build();
+        assert_eq!(
+            json!(body),
+            json!({"receive_id": "oc_xxx", "msg_type": "text", "content": "{\"text\":\"hello\"}", "uuid": null})
+        );
+
+        let req = ReplyMessageRequest::builder()
+            .message_id("om_xxx")
+            .request_body(
+                ReplyMessageRequestBody::builder()
+                    .message(MessageText::new("hello"))
+                    .reply_in_thread(true)
+                    .build(),
+            )
+            .build();
+        assert_eq!(req.message_id, "om_xxx");
+        assert_eq!(
+            serde_json::from_slice::<Value>(&req.api_req.body).unwrap(),
+            json!({"content": "{\"text\":\"hello\"}", "msg_type": "text", "reply_in_thread": true})
+        );
+
+        let req = UpdateMessageRequest::builder()
+            .message_id("om_xxx")
+            .request_body(
+                UpdateMessageRequestBody::builder()
+                    .message(MessageText::new("edited"))
+                    .build(),
+            )
+            .build();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&req.api_req.body).unwrap(),
+            json!({"msg_type": "text", "content": "{\"text\":\"edited\"}"})
+        );
+
+        let req = PatchMessageRequest::builder()
+            .message_id("om_xxx")
+            .request_body(
+                PatchMessageRequestBody::builder()
+                    .message(FeishuCard::default())
+                    .build(),
+            )
+            .build();
+        let body: Value = serde_json::from_slice(&req.api_req.body).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(body["content"].as_str().unwrap()).unwrap(),
+            json!({"i18n_header": {}, "i18n_elements": {}})
+        );
+
+        let req = PatchMessageRequest::builder()
+            .message_id("om_xxx")
+            .request_body(
+                PatchMessageRequestBody::builder()
+                    .message(MessageCardTemplate::new("ctp_xxx", json!({"name": "lark"})))
+                    .build(),
+            )
+            .build();
+        let body: Value = serde_json::from_slice(&req.api_req.body).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(body["content"].as_str().unwrap()).unwrap()["data"]
+                ["template_id"],
+            "ctp_xxx"
+        );
+
+        let req = MergeForwardMessageRequest::builder()
+            .receive_id_type("chat_id")
+            .uuid("uuid_1")
+            .request_body(
+                MergeForwardMessageRequestBody::builder()
+                    .receive_id("oc_xxx")
+                    .add_message_id("om_1")
+                    .add_message_id("om_2")
+                    .build(),
+            )
+            .build();
+        assert_eq!(req.api_req.query_params["receive_id_type"], "chat_id");
+        assert_eq!(req.api_req.query_params["uuid"], "uuid_1");
+        assert_eq!(
+            serde_json::from_slice::<Value>(&req.api_req.body).unwrap(),
+            json!({"receive_id": "oc_xxx", "message_id_list": ["om_1", "om_2"]})
+        );
+
+        let req = GetMessageRequest::builder()
+            .message_id("om_xxx")
+            .user_id_type("open_id")
+            .build();
+        assert_eq!(req.message_id, "om_xxx");
+        assert_eq!(req.api_req.query_params["user_id_type"], "open_id");
+    }
+
+    #[tokio::test]
+    async fn test_message_routes() {
+        let (base_url, routes) = api_server(10).await;
+        let service = MessageService {
+            config: Config {
+                app_id: "cli_9f427eec54ae901b".to_string(),
+                app_secret: "app_secret".into(),
+                domain: Domain::Custom(base_url),
+                enable_token_cache: false,
+                ..Default::default()
+            },
+        };
+        let option = RequestOption::builder()
+            .tenant_access_token("t-xxx")
+            .build();
+
+        let req = ReplyMessageRequest::builder().message_id("om_1").build();
+        service.reply(req, Some(option.clone())).await.unwrap();
+        let req = UpdateMessageRequest::builder().message_id("om_2").build();
+        service.update(req, Some(option.clone())).await.unwrap();
+        let req = PatchMessageRequest::builder().message_id("om_3").build();
+        service.patch(req, Some(option.clone())).await.unwrap();
+        let req = DeleteMessageRequest::builder().message_id("om_4").build();
+        service.delete(req, Some(option.clone())).await.unwrap();
+        let req = ForwardMessageRequest::builder().message_id("om_5").build();
+        service.forward(req, Some(option.clone())).await.unwrap();
+        let req = MergeForwardMessageRequest::builder().build();
+        service
+            .merge_forward(req, Some(option.clone()))
+            .await
+            .unwrap();
+        let req = GetMessageRequest::builder().message_id("om_6").build();
+        service.get(req, Some(option.clone())).await.unwrap();
+        let urgent = || UrgentMessageRequest::builder().message_id("om_7").build();
+        service
+            .urgent_app(urgent(), Some(option.clone()))
+            .await
+            .unwrap();
+        service
+            .urgent_sms(urgent(), Some(option.clone()))
+            .await
+            .unwrap();
+        service
+            .urgent_phone(urgent(), Some(option.clone()))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            *routes.lock().unwrap(),
+            [
+                "POST /open-apis/im/v1/messages/om_1/reply",
+                "PUT /open-apis/im/v1/messages/om_2",
+                "PATCH /open-apis/im/v1/messages/om_3",
+                "DELETE /open-apis/im/v1/messages/om_4",
+                "POST /open-apis/im/v1/messages/om_5/forward",
+                "POST /open-apis/im/v1/messages/merge_forward",
+                "GET /open-apis/im/v1/messages/om_6",
+                "PATCH /open-apis/im/v1/messages/om_7/urgent_app",
+                "PATCH /open-apis/im/v1/messages/om_7/urgent_sms",
+                "PATCH /open-apis/im/v1/messages/om_7/urgent_phone",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_urgent_message_request() {
+        let req = UrgentMessageRequest::builder()
+            .message_id("om_xxx")
+            .user_id_type(UserIdType::OpenId)
+            .user_id_list(vec!["ou_1".to_string()])
+            .add_user_id("ou_2")
+            .build();
+        assert_eq!(req.message_id, "om_xxx");
+        assert_eq!(req.api_req.query_params["user_id_type"], "open_id");
+        assert_eq!(
+            serde_json::from_slice::<Value>(&req.api_req.body).unwrap(),
+            json!({"user_id_list": ["ou_1", "ou_2"]})
+        );
+
+        let data: UrgentMessageRespData =
+            serde_json::from_value(json!({"invalid_user_id_list": ["ou_2"]})).unwrap();
+        assert_eq!(data.invalid_user_id_list, ["ou_2"]);
+        let data: UrgentMessageRespData = serde_json::from_value(json!({})).unwrap();
+        assert!(data.invalid_user_id_list.is_empty());
+
+        let req = UrgentMessageRequest::builder()
+            .message_id("om_xxx")
+            .add_user_id("ou_1")
+            .build();
+        assert_eq!(req.api_req.query_params["user_id_type"], "open_id");
+    }
 }
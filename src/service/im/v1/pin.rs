@@ -0,0 +1,357 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    api_req::ApiRequest,
+    api_resp::{ApiResponseTrait, BaseResponse, RawResponse, ResponseFormat},
+    config::Config,
+    constants::AccessTokenType,
+    http::Transport,
+    req_option::RequestOption,
+    SDKResult,
+};
+
+/// Pin 消息
+pub struct PinService {
+    pub config: Config,
+}
+
+impl PinService {
+    /// Pin 消息
+    ///
+    /// Pin 一条指定的消息，Pin 后会话内所有成员可见。
+    /// https://open.feishu.cn/document/server-docs/im-v1/pin/create
+    pub async fn create(
+        &self,
+        create_pin_request: CreatePinRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<CreatePinRespData>> {
+        let mut api_req = create_pin_request.api_req;
+        api_req.http_method = Method::POST;
+        api_req.api_path = "/open-apis/im/v1/pins".to_string();
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant, AccessTokenType::User];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    /// 移除 Pin 消息
+    ///
+    /// https://open.feishu.cn/document/server-docs/im-v1/pin/delete
+    pub async fn delete(
+        &self,
+        delete_pin_request: DeletePinRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<RawResponse>> {
+        let mut api_req = delete_pin_request.api_req;
+        api_req.http_method = Method::DELETE;
+        api_req.api_path = format!("/open-apis/im/v1/pins/{}", delete_pin_request.message_id);
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant, AccessTokenType::User];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    /// 获取群内 Pin 消息
+    ///
+    /// 获取所在群内指定时间范围内的所有 Pin 消息，按 Pin 的创建时间降序排列。
+    /// https://open.feishu.cn/document/server-docs/im-v1/pin/list
+    pub async fn list(
+        &self,
+        list_pin_request: ListPinRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<ListPinRespData>> {
+        let mut api_req = list_pin_request.api_req;
+        api_req.http_method = Method::GET;
+        api_req.api_path = "/open-apis/im/v1/pins".to_string();
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant, AccessTokenType::User];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    pub fn list_iter(
+        &self,
+        list_pin_request: ListPinRequest,
+        option: Option<RequestOption>,
+    ) -> ListPinIterator<'_> {
+        ListPinIterator {
+            service: self,
+            request: list_pin_request,
+            option,
+            has_more: true,
+        }
+    }
+}
+
+pub struct ListPinIterator<'a> {
+    service: &'a PinService,
+    request: ListPinRequest,
+    option: Option<RequestOption>,
+    has_more: bool,
+}
+
+impl ListPinIterator<'_> {
+    pub async fn next(&mut self) -> Option<Vec<Pin>> {
+        if !self.has_more {
+            return None;
+        }
+        match self
+            .service
+            .list(self.request.clone(), self.option.clone())
+            .await
+        {
+            Ok(resp) => match resp.data {
+                Some(data) => {
+                    self.has_more = data.has_more;
+                    if let Some(page_token) = data.page_token.filter(|_| data.has_more) {
+                        self.request
+                            .api_req
+                            .query_params
+                            .insert("page_token".to_string(), page_token);
+                        Some(data.items)
+                    } else if data.items.is_empty() {
+                        None
+                    } else {
+                        self.has_more = false;
+                        Some(data.items)
+                    }
+                }
+                None => None,
+            },
+            Err(_) => None,
+        }
+    }
+}
+
+/// Pin 消息请求
+#[derive(Default)]
+pub struct CreatePinRequest {
+    api_req: ApiRequest,
+}
+
+impl CreatePinRequest {
+    pub fn builder() -> CreatePinRequestBuilder {
+        CreatePinRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct CreatePinRequestBuilder {
+    request: CreatePinRequest,
+}
+
+impl CreatePinRequestBuilder {
+    /// 待 Pin 的消息ID
+    ///
+    /// 示例值："om_dc13264520392913993dd051dba21dcf"
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        let body = CreatePinRequestBody {
+            message_id: message_id.to_string(),
+        };
+        self.request.api_req.body = serde_json::to_vec(&body).unwrap();
+        self
+    }
+
+    pub fn build(self) -> CreatePinRequest {
+        self.request
+    }
+}
+
+/// Pin 消息 请求体
+#[derive(Debug, Serialize)]
+struct CreatePinRequestBody {
+    message_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CreatePinRespData {
+    /// Pin 的操作信息
+    pub pin: Pin,
+}
+
+impl ApiResponseTrait for CreatePinRespData {
+    fn data_format() -> ResponseFormat {
+        ResponseFormat::Data
+    }
+}
+
+/// 移除 Pin 消息请求
+#[derive(Default)]
+pub struct DeletePinRequest {
+    api_req: ApiRequest,
+    /// 待移除 Pin 的消息ID
+    message_id: String,
+}
+
+impl DeletePinRequest {
+    pub fn builder() -> DeletePinRequestBuilder {
+        DeletePinRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct DeletePinRequestBuilder {
+    request: DeletePinRequest,
+}
+
+impl DeletePinRequestBuilder {
+    /// 待移除 Pin 的消息ID
+    ///
+    /// 示例值："om_dc13264520392913993dd051dba21dcf"
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id = message_id.to_string();
+        self
+    }
+
+    pub fn build(self) -> DeletePinRequest {
+        self.request
+    }
+}
+
+/// 获取群内 Pin 消息请求
+#[derive(Default, Clone)]
+pub struct ListPinRequest {
+    api_req: ApiRequest,
+}
+
+impl ListPinRequest {
+    pub fn builder() -> ListPinRequestBuilder {
+        ListPinRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct ListPinRequestBuilder {
+    request: ListPinRequest,
+}
+
+impl ListPinRequestBuilder {
+    /// 待获取 Pin 消息的群ID
+    ///
+    /// 示例值："oc_234jsi43d3ssi993d43545f"
+    pub fn chat_id(mut self, chat_id: impl ToString) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("chat_id".to_string(), chat_id.to_string());
+        self
+    }
+
+    /// Pin 的起始时间（毫秒级时间戳），默认为群聊中第一条 Pin 的创建时间
+    pub fn start_time(mut self, start_time: i64) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("start_time".to_string(), start_time.to_string());
+        self
+    }
+
+    /// Pin 的结束时间（毫秒级时间戳），默认为当前时间
+    pub fn end_time(mut self, end_time: i64) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("end_time".to_string(), end_time.to_string());
+        self
+    }
+
+    /// 分页大小
+    ///
+    /// 默认值：20
+    pub fn page_size(mut self, page_size: i32) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("page_size".to_string(), page_size.to_string());
+        self
+    }
+
+    /// 分页标记，第一次请求不填，表示从头开始遍历；分页查询结果还有更多项时会同时返回新的
+    /// page_token，下次遍历可采用该page_token 获取查询结果
+    pub fn page_token(mut self, page_token: impl ToString) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("page_token".to_string(), page_token.to_string());
+        self
+    }
+
+    pub fn build(self) -> ListPinRequest {
+        self.request
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListPinRespData {
+    /// Pin 的操作信息列表
+    #[serde(default)]
+    pub items: Vec<Pin>,
+    /// 是否还有更多项
+    pub has_more: bool,
+    /// 分页标记，当 has_more 为 true 时，会同时返回新的 page_token，否则不返回 page_token
+    pub page_token: Option<String>,
+}
+
+impl ApiResponseTrait for ListPinRespData {
+    fn data_format() -> ResponseFormat {
+        ResponseFormat::Data
+    }
+}
+
+/// Pin 的操作信息
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Pin {
+    /// Pin 的消息ID
+    pub message_id: String,
+    /// Pin 消息所在的群ID
+    pub chat_id: String,
+    /// Pin 的操作人ID
+    pub operator_id: String,
+    /// Pin 的操作人ID类型, 用户为 open_id, 机器人为 app_id
+    pub operator_id_type: String,
+    /// Pin 的创建时间（毫秒级时间戳）
+    pub create_time: String,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+
+    use crate::service::im::v1::pin::{CreatePinRequest, ListPinRequest, ListPinRespData};
+
+    #[test]
+    fn test_pin_request() {
+        let req = CreatePinRequest::builder().message_id("om_xxx").build();
+        assert_eq!(
+            serde_json::from_slice::<Value>(&req.api_req.body).unwrap(),
+            json!({"message_id": "om_xxx"})
+        );
+
+        let req = ListPinRequest::builder()
+            .chat_id("oc_xxx")
+            .start_time(1658632251800)
+            .page_size(20)
+            .build();
+        assert_eq!(req.api_req.query_params["chat_id"], "oc_xxx");
+        assert_eq!(req.api_req.query_params["start_time"], "1658632251800");
+
+        let data: ListPinRespData = serde_json::from_value(json!({
+            "items": [{
+                "message_id": "om_dc13264520392913993dd051dba21dcf",
+                "chat_id": "oc_a0553eda9014c201e6969b478895c230",
+                "operator_id": "ou_7d8a6e6df7621556ce0d21922b676706ccs",
+                "operator_id_type": "open_id",
+                "create_time": "1615380573211"
+            }],
+            "has_more": true,
+            "page_token": "GxmvlNRvP0NdQZpa7yIqf_Lv_QuBwTQ8tXkX7w-irAghVD_TvuYd1aoJ1LQph86O-XImC4X9j9FhUPhXQDvtrQ=="
+        }))
+        .unwrap();
+        assert_eq!(data.items[0].operator_id_type, "open_id");
+        assert!(data.has_more);
+    }
+}
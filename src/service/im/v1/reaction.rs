@@ -0,0 +1,407 @@
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        api_req::ApiRequest,
+        api_resp::{ApiResponseTrait, BaseResponse, ResponseFormat},
+        config::Config,
+        constants::AccessTokenType,
+        http::Transport,
+        req_option::RequestOption,
+        SDKResult,
+    },
+    service::common::{EmojiType, UserIdType},
+};
+
+/// 表情回复
+pub struct ReactionService {
+    pub config: Config,
+}
+
+impl ReactionService {
+    /// 添加消息表情回复
+    ///
+    /// https://open.feishu.cn/document/server-docs/im-v1/message-reaction/create
+    pub async fn create(
+        &self,
+        create_reaction_request: CreateReactionRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<MessageReaction>> {
+        let mut api_req = create_reaction_request.api_req;
+        api_req.http_method = Method::POST;
+        api_req.api_path = format!(
+            "/open-apis/im/v1/messages/{}/reactions",
+            create_reaction_request.message_id
+        );
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant, AccessTokenType::User];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    /// 获取消息表情回复
+    ///
+    /// https://open.feishu.cn/document/server-docs/im-v1/message-reaction/list
+    pub async fn list(
+        &self,
+        list_reaction_request: ListReactionRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<ListReactionRespData>> {
+        let mut api_req = list_reaction_request.api_req;
+        api_req.http_method = Method::GET;
+        api_req.api_path = format!(
+            "/open-apis/im/v1/messages/{}/reactions",
+            list_reaction_request.message_id
+        );
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant, AccessTokenType::User];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+
+    pub fn list_iter(
+        &self,
+        list_reaction_request: ListReactionRequest,
+        option: Option<RequestOption>,
+    ) -> ListReactionIterator<'_> {
+        ListReactionIterator {
+            service: self,
+            request: list_reaction_request,
+            option,
+            has_more: true,
+        }
+    }
+
+    /// 删除消息表情回复, 只能删除自己添加的表情回复
+    ///
+    /// https://open.feishu.cn/document/server-docs/im-v1/message-reaction/delete
+    pub async fn delete(
+        &self,
+        delete_reaction_request: DeleteReactionRequest,
+        option: Option<RequestOption>,
+    ) -> SDKResult<BaseResponse<MessageReaction>> {
+        let mut api_req = delete_reaction_request.api_req;
+        api_req.http_method = Method::DELETE;
+        api_req.api_path = format!(
+            "/open-apis/im/v1/messages/{}/reactions/{}",
+            delete_reaction_request.message_id, delete_reaction_request.reaction_id
+        );
+        api_req.supported_access_token_types = vec![AccessTokenType::Tenant, AccessTokenType::User];
+
+        let api_resp = Transport::request(api_req, &self.config, option).await?;
+
+        Ok(api_resp)
+    }
+}
+
+pub struct ListReactionIterator<'a> {
+    service: &'a ReactionService,
+    request: ListReactionRequest,
+    option: Option<RequestOption>,
+    has_more: bool,
+}
+
+impl ListReactionIterator<'_> {
+    pub async fn next(&mut self) -> Option<Vec<MessageReaction>> {
+        if !self.has_more {
+            return None;
+        }
+        match self
+            .service
+            .list(self.request.clone(), self.option.clone())
+            .await
+        {
+            Ok(resp) => match resp.data {
+                Some(data) => {
+                    self.has_more = data.has_more;
+                    if let Some(page_token) = data.page_token.filter(|_| data.has_more) {
+                        self.request
+                            .api_req
+                            .query_params
+                            .insert("page_token".to_string(), page_token);
+                        Some(data.items)
+                    } else if data.items.is_empty() {
+                        None
+                    } else {
+                        self.has_more = false;
+                        Some(data.items)
+                    }
+                }
+                None => None,
+            },
+            Err(_) => None,
+        }
+    }
+}
+
+/// 添加消息表情回复请求
+#[derive(Default)]
+pub struct CreateReactionRequest {
+    api_req: ApiRequest,
+    /// 待添加表情回复的消息ID
+    message_id: String,
+}
+
+impl CreateReactionRequest {
+    pub fn builder() -> CreateReactionRequestBuilder {
+        CreateReactionRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct CreateReactionRequestBuilder {
+    request: CreateReactionRequest,
+}
+
+impl CreateReactionRequestBuilder {
+    /// 待添加表情回复的消息ID
+    ///
+    /// 示例值："om_a8f2294b************a1a38afaac9d"
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id = message_id.to_string();
+        self
+    }
+
+    /// 表情类型
+    ///
+    /// 示例值："SMILE"
+    pub fn emoji_type(mut self, emoji_type: impl Into<EmojiType>) -> Self {
+        let body = CreateReactionRequestBody {
+            reaction_type: Emoji {
+                emoji_type: emoji_type.into(),
+            },
+        };
+        self.request.api_req.body = serde_json::to_vec(&body).unwrap();
+        self
+    }
+
+    pub fn build(self) -> CreateReactionRequest {
+        self.request
+    }
+}
+
+/// 添加消息表情回复 请求体
+#[derive(Debug, Serialize)]
+struct CreateReactionRequestBody {
+    reaction_type: Emoji,
+}
+
+/// 获取消息表情回复请求
+#[derive(Default, Clone)]
+pub struct ListReactionRequest {
+    api_req: ApiRequest,
+    /// 待查询的消息ID
+    message_id: String,
+}
+
+impl ListReactionRequest {
+    pub fn builder() -> ListReactionRequestBuilder {
+        ListReactionRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct ListReactionRequestBuilder {
+    request: ListReactionRequest,
+}
+
+impl ListReactionRequestBuilder {
+    /// 待查询的消息ID
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id = message_id.to_string();
+        self
+    }
+
+    /// 只查询指定类型的表情回复, 不填时返回全部类型
+    pub fn reaction_type(mut self, reaction_type: impl Into<EmojiType>) -> Self {
+        self.request.api_req.query_params.insert(
+            "reaction_type".to_string(),
+            reaction_type.into().to_string(),
+        );
+        self
+    }
+
+    /// 分页标记，第一次请求不填，表示从头开始遍历；分页查询结果还有更多项时会同时返回新的
+    /// page_token，下次遍历可采用该page_token 获取查询结果
+    pub fn page_token(mut self, page_token: impl ToString) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("page_token".to_string(), page_token.to_string());
+        self
+    }
+
+    /// 分页大小
+    ///
+    /// 默认值：20
+    pub fn page_size(mut self, page_size: i32) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("page_size".to_string(), page_size.to_string());
+        self
+    }
+
+    /// 操作人的用户 ID 类型, 仅在操作人为用户时生效
+    pub fn user_id_type(mut self, user_id_type: impl Into<UserIdType>) -> Self {
+        self.request
+            .api_req
+            .query_params
+            .insert("user_id_type".to_string(), user_id_type.into().to_string());
+        self
+    }
+
+    pub fn build(self) -> ListReactionRequest {
+        self.request
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ListReactionRespData {
+    /// 表情回复列表
+    #[serde(default)]
+    pub items: Vec<MessageReaction>,
+    /// 是否还有更多项
+    pub has_more: bool,
+    /// 分页标记，当 has_more 为 true 时，会同时返回新的 page_token，否则不返回 page_token
+    pub page_token: Option<String>,
+}
+
+impl ApiResponseTrait for ListReactionRespData {
+    fn data_format() -> ResponseFormat {
+        ResponseFormat::Data
+    }
+}
+
+/// 删除消息表情回复请求
+#[derive(Default)]
+pub struct DeleteReactionRequest {
+    api_req: ApiRequest,
+    /// 待删除表情回复的消息ID
+    message_id: String,
+    /// 待删除的表情回复ID
+    reaction_id: String,
+}
+
+impl DeleteReactionRequest {
+    pub fn builder() -> DeleteReactionRequestBuilder {
+        DeleteReactionRequestBuilder::default()
+    }
+}
+
+#[derive(Default)]
+pub struct DeleteReactionRequestBuilder {
+    request: DeleteReactionRequest,
+}
+
+impl DeleteReactionRequestBuilder {
+    /// 待删除表情回复的消息ID
+    pub fn message_id(mut self, message_id: impl ToString) -> Self {
+        self.request.message_id = message_id.to_string();
+        self
+    }
+
+    /// 待删除的表情回复ID, 添加表情回复时返回
+    pub fn reaction_id(mut self, reaction_id: impl ToString) -> Self {
+        self.request.reaction_id = reaction_id.to_string();
+        self
+    }
+
+    pub fn build(self) -> DeleteReactionRequest {
+        self.request
+    }
+}
+
+/// 消息表情回复
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MessageReaction {
+    /// 表情回复ID
+    pub reaction_id: String,
+    /// 添加表情回复的操作人
+    pub operator: Operator,
+    /// 添加表情回复的时间戳（毫秒）
+    pub action_time: String,
+    /// 表情类型
+    pub reaction_type: Emoji,
+}
+
+impl ApiResponseTrait for MessageReaction {
+    fn data_format() -> ResponseFormat {
+        ResponseFormat::Data
+    }
+}
+
+/// 表情回复的操作人
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Operator {
+    /// 操作人ID
+    pub operator_id: String,
+    /// 操作人身份, app 或 user
+    pub operator_type: String,
+}
+
+/// 表情回复的资源类型
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Emoji {
+    /// 表情类型
+    pub emoji_type: EmojiType,
+}
+
+#[cfg(test)]
+mod test {
+    use serde_json::{json, Value};
+
+    use crate::service::{
+        common::EmojiType,
+        im::v1::reaction::{CreateReactionRequest, ListReactionRequest, ListReactionRespData},
+    };
+
+    #[test]
+    fn test_reaction_request() {
+        let req = CreateReactionRequest::builder()
+            .message_id("om_xxx")
+            .emoji_type(EmojiType::ThumbsUp)
+            .build();
+        assert_eq!(req.message_id, "om_xxx");
+        assert_eq!(
+            serde_json::from_slice::<Value>(&req.api_req.body).unwrap(),
+            json!({"reaction_type": {"emoji_type": "THUMBSUP"}})
+        );
+
+        let req = ListReactionRequest::builder()
+            .message_id("om_xxx")
+            .reaction_type("Lemon")
+            .page_size(50)
+            .build();
+        assert_eq!(req.api_req.query_params["reaction_type"], "Lemon");
+        assert_eq!(req.api_req.query_params["page_size"], "50");
+    }
+
+    #[test]
+    fn test_list_reaction_resp() {
+        let data: ListReactionRespData = serde_json::from_value(json!({
+            "items": [{
+                "reaction_id": "ZCaCIjUBVVWSrm5L-3ZTw*************sNa8dHVplEzzSfJVUVLMLcS_",
+                "operator": {"operator_id": "ou_ff0b7ba35fb********67dfc8b885136", "operator_type": "user"},
+                "action_time": "1663054162546",
+                "reaction_type": {"emoji_type": "SMILE"}
+            }, {
+                "reaction_id": "ZCaCIjUBVVWSrm5L-3ZTw*************sNa8dHVplEzzSfJVUVLMLcS0",
+                "operator": {"operator_id": "cli_a1b2c3d4e5", "operator_type": "app"},
+                "action_time": "1663054162547",
+                "reaction_type": {"emoji_type": "SomeNewEmoji"}
+            }],
+            "has_more": false
+        }))
+        .unwrap();
+        assert_eq!(data.items[0].reaction_type.emoji_type, EmojiType::Smile);
+        assert_eq!(
+            data.items[1].reaction_type.emoji_type,
+            EmojiType::Custom("SomeNewEmoji".to_string())
+        );
+        assert!(data.page_token.is_none());
+    }
+}
@@ -1,5 +1,6 @@
+pub mod bitable;
+pub mod common;
 pub mod drive;
 pub mod im;
 pub mod search;
 pub mod sheets;
-pub mod bitable;
\ No newline at end of file
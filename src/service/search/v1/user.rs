@@ -2,16 +2,11 @@ use log::error;
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
+use crate::core::api_resp::BaseResponse;
 use crate::core::{
-    api_req::ApiRequest,
-    api_resp::{ ApiResponseTrait},
-    config::Config,
-    constants::AccessTokenType,
-    http::Transport,
-    req_option::RequestOption,
-    SDKResult,
+    api_req::ApiRequest, api_resp::ApiResponseTrait, config::Config, constants::AccessTokenType,
+    http::Transport, req_option::RequestOption, SDKResult,
 };
-use crate::core::api_resp::BaseResponse;
 
 pub struct UserService {
     config: Config,
@@ -42,7 +37,7 @@ impl UserService {
         &self,
         search_user_request: SearchUserRequest,
         option: Option<RequestOption>,
-    ) -> SearchUserIterator {
+    ) -> SearchUserIterator<'_> {
         SearchUserIterator {
             user_service: self,
             request: search_user_request,